@@ -6,47 +6,14 @@ use plexus::integration::theon;
 use nalgebra::Point3;
 use pictor::pipeline::{self, Vertex};
 use plexus::buffer::MeshBuffer3;
-use plexus::geometry::AsPositionMut;
-use plexus::graph::{EdgeMidpoint, FaceView, GraphData, MeshGraph};
+use plexus::graph::MeshGraph;
 use plexus::prelude::*;
 use plexus::primitive::Tetragon;
-use smallvec::SmallVec;
 use theon::space::{EuclideanSpace, VectorSpace};
 
 type E3 = Point3<f32>;
 type Buffer = MeshBuffer3<u32, Vertex>;
 
-pub trait Circumscribe<G> {
-    fn circumscribe(self) -> Self;
-}
-
-impl<'a, G> Circumscribe<G> for FaceView<&'a mut MeshGraph<G>>
-where
-    G: EdgeMidpoint + GraphData,
-    G::Vertex: AsPositionMut,
-{
-    // Subdivide the face such that a similar polygon is formed within its
-    // perimeter.
-    fn circumscribe(self) -> Self {
-        // Split each edge, stashing the vertex key and moving to the next arc.
-        let arity = self.arity();
-        let mut arc = self.into_arc();
-        let mut splits = SmallVec::<[_; 4]>::with_capacity(arity);
-        for _ in 0..arity {
-            let vertex = arc.split_at_midpoint();
-            splits.push(vertex.key());
-            arc = vertex.into_outgoing_arc().into_next_arc();
-        }
-        // Split faces along the vertices from each arc split.
-        let mut face = arc.into_face().unwrap();
-        for (a, b) in splits.into_iter().perimeter() {
-            face = face.split(ByKey(a), ByKey(b)).unwrap().into_face().unwrap();
-        }
-        // Return the face forming the similar polygon.
-        face
-    }
-}
-
 fn main() {
     let from = Point3::new(-0.9, 3.1, 2.4);
     let to = Point3::new(0.0, 1.0, 0.0);
@@ -62,9 +29,13 @@ fn main() {
         let key = graph.faces().nth(0).unwrap().key();
         let mut face = graph.face_mut(key).unwrap();
 
-        // Circumscribe and extrude the face recursively.
+        // Inset and extrude the face recursively.
         for _ in 0..5 {
-            face = face.circumscribe().extrude_with_offset(0.5).unwrap();
+            face = face
+                .inset_with(0.15, 0.0)
+                .unwrap()
+                .extrude_with_offset(0.5)
+                .unwrap();
         }
 
         // Convert the graph into a buffer.
@@ -0,0 +1,598 @@
+//! [glTF] encoding.
+//!
+//! This module provides support for writing binary [glTF] (GLB) via the
+//! [`ToGltf`] trait. This trait can be used with an encoder to write
+//! triangular [`MeshBuffer`]s as self-contained GLB data with a single mesh.
+//!
+//! Vertex positions are always written. Normals (`NORMAL`) and colors
+//! (`COLOR_0`) are written if the encoder provides them for every vertex. Index
+//! data is written using the narrowest component type permitted by the index
+//! type of the buffer. Reading [glTF] is not supported.
+//!
+//! # Examples
+//!
+//! Writing a [`MeshBuffer`] as GLB:
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::buffer::MeshBuffer3;
+//! use plexus::encoding::gltf::{PositionEncoding, ToGltf};
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::sphere::UvSphere;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let buffer: MeshBuffer3<u16, E3> = UvSphere::new(16, 16)
+//!     .polygons::<Position<E3>>()
+//!     .triangulate()
+//!     .collect();
+//!
+//! let mut glb = Vec::new();
+//! buffer
+//!     .to_glb(PositionEncoding::<E3>::default(), &mut glb)
+//!     .unwrap();
+//! ```
+//!
+//! [glTF]: https://www.khronos.org/gltf/
+//!
+//! [`ToGltf`]: crate::encoding::gltf::ToGltf
+//! [`MeshBuffer`]: crate::buffer::MeshBuffer
+
+#![cfg(feature = "encoding-gltf")]
+
+use num::cast;
+use num::NumCast;
+use std::io::{self, Write};
+use std::marker::PhantomData;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use theon::AsPosition;
+use thiserror::Error;
+use typenum::U3;
+
+use crate::buffer::{MeshBuffer, MeshBuffer3};
+use crate::index::Flat3;
+
+const GLB_MAGIC: u32 = 0x4654_6c67;
+const GLB_VERSION: u32 = 2;
+const CHUNK_JSON: u32 = 0x4e4f_534a;
+const CHUNK_BIN: u32 = 0x004e_4942;
+
+const COMPONENT_UNSIGNED_SHORT: u32 = 5123;
+const COMPONENT_UNSIGNED_INT: u32 = 5125;
+const COMPONENT_FLOAT: u32 = 5126;
+
+const TARGET_ARRAY_BUFFER: u32 = 34962;
+const TARGET_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// Errors concerning the [glTF] encoding.
+///
+/// [glTF]: https://www.khronos.org/gltf/
+#[derive(Debug, Error)]
+pub enum GltfError {
+    /// The mesh has no vertices or faces.
+    #[error("mesh is empty")]
+    Empty,
+    /// An index into vertex data is out of bounds.
+    #[error("index into vertex data out of bounds")]
+    IndexOutOfBounds,
+    /// An index cannot be represented by the component type of index data.
+    #[error("index overflow")]
+    IndexOverflow,
+    /// Data could not be converted to its encoding.
+    #[error("failed to convert data")]
+    Encoding,
+    #[error("failed to write data")]
+    Io(#[from] io::Error),
+}
+
+/// Type of encoded index data.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum IndexType {
+    U16,
+    U32,
+}
+
+/// Index type that can be written as [glTF] index data.
+///
+/// [glTF]: https://www.khronos.org/gltf/
+pub trait IndexComponent: Copy + NumCast {
+    /// Gets the type of encoded index data for the given number of vertices.
+    fn index_type(count: usize) -> IndexType;
+}
+
+impl IndexComponent for u16 {
+    fn index_type(_: usize) -> IndexType {
+        IndexType::U16
+    }
+}
+
+impl IndexComponent for u32 {
+    fn index_type(_: usize) -> IndexType {
+        IndexType::U32
+    }
+}
+
+impl IndexComponent for usize {
+    fn index_type(count: usize) -> IndexType {
+        // The maximum value of a component type is reserved for primitive
+        // restart and must not be used as an index.
+        if count <= u16::MAX as usize {
+            IndexType::U16
+        }
+        else {
+            IndexType::U32
+        }
+    }
+}
+
+pub trait VertexEncoder<G> {
+    fn encode_position(&self, vertex: &G) -> Result<[f32; 3], GltfError>;
+
+    /// Encodes the normal of a vertex.
+    ///
+    /// Normals are only written if this function returns a normal for every
+    /// vertex.
+    fn encode_normal(&self, _: &G) -> Result<Option<[f32; 3]>, GltfError> {
+        Ok(None)
+    }
+
+    /// Encodes the linear RGBA color of a vertex.
+    ///
+    /// Colors are only written if this function returns a color for every
+    /// vertex.
+    fn encode_color(&self, _: &G) -> Result<Option<[f32; 4]>, GltfError> {
+        Ok(None)
+    }
+}
+
+/// Conversion into [glTF].
+///
+/// [glTF]: https://www.khronos.org/gltf/
+pub trait ToGltf<E> {
+    /// Writes binary [glTF] (GLB) data using the given encoder.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh is empty or if data cannot be encoded or
+    /// written.
+    ///
+    /// [glTF]: https://www.khronos.org/gltf/
+    fn to_glb<W>(&self, encoder: E, write: W) -> Result<usize, GltfError>
+    where
+        W: Write;
+}
+
+impl<E, N, G> ToGltf<E> for MeshBuffer<Flat3<N>, G>
+where
+    E: VertexEncoder<G>,
+    N: IndexComponent,
+{
+    fn to_glb<W>(&self, encoder: E, write: W) -> Result<usize, GltfError>
+    where
+        W: Write,
+    {
+        encode_glb(
+            &encoder,
+            self.as_index_slice(),
+            self.as_vertex_slice(),
+            write,
+        )
+    }
+}
+
+impl<E, N, G> ToGltf<E> for MeshBuffer3<N, G>
+where
+    E: VertexEncoder<G>,
+    N: IndexComponent,
+{
+    fn to_glb<W>(&self, encoder: E, write: W) -> Result<usize, GltfError>
+    where
+        W: Write,
+    {
+        let indices = self
+            .as_index_slice()
+            .iter()
+            .flat_map(|trigon| trigon.as_ref().iter().cloned())
+            .collect::<Vec<_>>();
+        encode_glb(&encoder, &indices, self.as_vertex_slice(), write)
+    }
+}
+
+/// Encoding of vertex positions.
+///
+/// Only positions are written. Implement [`VertexEncoder`] to write normals and
+/// colors.
+///
+/// [`VertexEncoder`]: crate::encoding::gltf::VertexEncoder
+pub struct PositionEncoding<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for PositionEncoding<T> {
+    fn default() -> Self {
+        PositionEncoding {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, G> VertexEncoder<G> for PositionEncoding<T>
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+    G: AsPosition<Position = T>,
+{
+    fn encode_position(&self, vertex: &G) -> Result<[f32; 3], GltfError> {
+        let (x, y, z) = vertex.as_position().into_xyz();
+        Ok([
+            num_cast_scalar(x)?,
+            num_cast_scalar(y)?,
+            num_cast_scalar(z)?,
+        ])
+    }
+}
+
+struct View {
+    offset: usize,
+    len: usize,
+    target: u32,
+}
+
+impl View {
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{}}}"#,
+            self.offset, self.len, self.target,
+        )
+    }
+}
+
+struct Accessor {
+    view: usize,
+    component: u32,
+    count: usize,
+    kind: &'static str,
+    bounds: Option<([f32; 3], [f32; 3])>,
+}
+
+impl Accessor {
+    fn to_json(&self) -> String {
+        let bounds = match self.bounds {
+            Some(([ax, ay, az], [bx, by, bz])) => format!(
+                r#","min":[{},{},{}],"max":[{},{},{}]"#,
+                ax, ay, az, bx, by, bz,
+            ),
+            _ => String::new(),
+        };
+        format!(
+            r#"{{"bufferView":{},"componentType":{},"count":{},"type":"{}"{}}}"#,
+            self.view, self.component, self.count, self.kind, bounds,
+        )
+    }
+}
+
+fn encode_glb<E, N, G, W>(
+    encoder: &E,
+    indices: &[N],
+    vertices: &[G],
+    mut write: W,
+) -> Result<usize, GltfError>
+where
+    E: VertexEncoder<G>,
+    N: IndexComponent,
+    W: Write,
+{
+    if indices.is_empty() || vertices.is_empty() {
+        return Err(GltfError::Empty);
+    }
+    let positions = vertices
+        .iter()
+        .map(|vertex| encoder.encode_position(vertex))
+        .collect::<Result<Vec<_>, _>>()?;
+    let normals = collect_attribute(vertices.iter().map(|vertex| encoder.encode_normal(vertex)))?;
+    let colors = collect_attribute(vertices.iter().map(|vertex| encoder.encode_color(vertex)))?;
+
+    let mut data = Vec::new();
+    let mut views = Vec::new();
+    let mut accessors = Vec::new();
+    let mut attributes = Vec::new();
+
+    let offset = data.len();
+    let component = match N::index_type(vertices.len()) {
+        IndexType::U16 => {
+            for index in indices {
+                let index: u16 = encode_index(*index, vertices.len())?;
+                data.extend_from_slice(&index.to_le_bytes());
+            }
+            COMPONENT_UNSIGNED_SHORT
+        }
+        IndexType::U32 => {
+            for index in indices {
+                let index: u32 = encode_index(*index, vertices.len())?;
+                data.extend_from_slice(&index.to_le_bytes());
+            }
+            COMPONENT_UNSIGNED_INT
+        }
+    };
+    views.push(View {
+        offset,
+        len: data.len() - offset,
+        target: TARGET_ELEMENT_ARRAY_BUFFER,
+    });
+    accessors.push(Accessor {
+        view: 0,
+        component,
+        count: indices.len(),
+        kind: "SCALAR",
+        bounds: None,
+    });
+    pad(&mut data, 0);
+
+    // Accessors for positions must provide bounds. These are computed from the
+    // encoded positions so that they are exact.
+    let mut lower = positions[0];
+    let mut upper = positions[0];
+    for position in &positions {
+        for (axis, scalar) in position.iter().enumerate() {
+            if !scalar.is_finite() {
+                return Err(GltfError::Encoding);
+            }
+            lower[axis] = lower[axis].min(*scalar);
+            upper[axis] = upper[axis].max(*scalar);
+        }
+    }
+    attributes.push(("POSITION", accessors.len()));
+    push_floats(
+        &mut data,
+        &mut views,
+        &mut accessors,
+        "VEC3",
+        positions.len(),
+        Some((lower, upper)),
+        positions
+            .iter()
+            .flat_map(|position| position.iter().cloned()),
+    );
+    if let Some(normals) = normals {
+        attributes.push(("NORMAL", accessors.len()));
+        push_floats(
+            &mut data,
+            &mut views,
+            &mut accessors,
+            "VEC3",
+            normals.len(),
+            None,
+            normals.iter().flat_map(|normal| normal.iter().cloned()),
+        );
+    }
+    if let Some(colors) = colors {
+        attributes.push(("COLOR_0", accessors.len()));
+        push_floats(
+            &mut data,
+            &mut views,
+            &mut accessors,
+            "VEC4",
+            colors.len(),
+            None,
+            colors.iter().flat_map(|color| color.iter().cloned()),
+        );
+    }
+
+    let json = format!(
+        concat!(
+            r#"{{"asset":{{"version":"2.0","generator":"plexus"}},"#,
+            r#""scene":0,"scenes":[{{"nodes":[0]}}],"nodes":[{{"mesh":0}}],"#,
+            r#""meshes":[{{"primitives":[{{"attributes":{{{}}},"indices":0,"mode":4}}]}}],"#,
+            r#""buffers":[{{"byteLength":{}}}],"bufferViews":[{}],"accessors":[{}]}}"#,
+        ),
+        attributes
+            .iter()
+            .map(|(name, index)| format!(r#""{}":{}"#, name, index))
+            .collect::<Vec<_>>()
+            .join(","),
+        data.len(),
+        views
+            .iter()
+            .map(View::to_json)
+            .collect::<Vec<_>>()
+            .join(","),
+        accessors
+            .iter()
+            .map(Accessor::to_json)
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    let mut json = json.into_bytes();
+    pad(&mut json, b' ');
+
+    let len = 12 + 8 + json.len() + 8 + data.len();
+    write.write_all(&GLB_MAGIC.to_le_bytes())?;
+    write.write_all(&GLB_VERSION.to_le_bytes())?;
+    write.write_all(&num_cast_len(len)?.to_le_bytes())?;
+    write.write_all(&num_cast_len(json.len())?.to_le_bytes())?;
+    write.write_all(&CHUNK_JSON.to_le_bytes())?;
+    write.write_all(&json)?;
+    write.write_all(&num_cast_len(data.len())?.to_le_bytes())?;
+    write.write_all(&CHUNK_BIN.to_le_bytes())?;
+    write.write_all(&data)?;
+    Ok(len)
+}
+
+fn push_floats<I>(
+    data: &mut Vec<u8>,
+    views: &mut Vec<View>,
+    accessors: &mut Vec<Accessor>,
+    kind: &'static str,
+    count: usize,
+    bounds: Option<([f32; 3], [f32; 3])>,
+    scalars: I,
+) where
+    I: IntoIterator<Item = f32>,
+{
+    let offset = data.len();
+    for scalar in scalars {
+        data.extend_from_slice(&scalar.to_le_bytes());
+    }
+    views.push(View {
+        offset,
+        len: data.len() - offset,
+        target: TARGET_ARRAY_BUFFER,
+    });
+    accessors.push(Accessor {
+        view: views.len() - 1,
+        component: COMPONENT_FLOAT,
+        count,
+        kind,
+        bounds,
+    });
+    pad(data, 0);
+}
+
+fn collect_attribute<I, T>(attributes: I) -> Result<Option<Vec<T>>, GltfError>
+where
+    I: IntoIterator<Item = Result<Option<T>, GltfError>>,
+{
+    let attributes = attributes.into_iter().collect::<Result<Vec<_>, _>>()?;
+    if attributes.iter().all(Option::is_none) {
+        Ok(None)
+    }
+    else {
+        // Attributes must be provided for either all vertices or none.
+        attributes
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .map(Some)
+            .ok_or(GltfError::Encoding)
+    }
+}
+
+fn encode_index<N, T>(index: N, count: usize) -> Result<T, GltfError>
+where
+    N: NumCast,
+    T: NumCast,
+{
+    let index: usize = cast::cast(index).ok_or(GltfError::IndexOverflow)?;
+    if index >= count {
+        return Err(GltfError::IndexOutOfBounds);
+    }
+    // The maximum value of the component type is reserved for primitive
+    // restart. Casting the successor of the index detects this value.
+    cast::cast::<_, T>(index + 1).ok_or(GltfError::IndexOverflow)?;
+    cast::cast(index).ok_or(GltfError::IndexOverflow)
+}
+
+fn pad(data: &mut Vec<u8>, byte: u8) {
+    while data.len() % 4 != 0 {
+        data.push(byte);
+    }
+}
+
+fn num_cast_len(len: usize) -> Result<u32, GltfError> {
+    cast::cast(len).ok_or(GltfError::Encoding)
+}
+
+fn num_cast_scalar<T, U>(value: T) -> Result<U, GltfError>
+where
+    T: NumCast,
+    U: NumCast,
+{
+    cast::cast(value).ok_or(GltfError::Encoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::N64;
+    use nalgebra::Point3;
+
+    use crate::buffer::{MeshBuffer, MeshBuffer3};
+    use crate::encoding::gltf::{GltfError, PositionEncoding, ToGltf, VertexEncoder};
+    use crate::index::Flat3;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<N64>;
+
+    struct ColorEncoding;
+
+    impl VertexEncoder<E3> for ColorEncoding {
+        fn encode_position(&self, vertex: &E3) -> Result<[f32; 3], GltfError> {
+            PositionEncoding::<E3>::default().encode_position(vertex)
+        }
+
+        fn encode_normal(&self, vertex: &E3) -> Result<Option<[f32; 3]>, GltfError> {
+            self.encode_position(vertex).map(Some)
+        }
+
+        fn encode_color(&self, _: &E3) -> Result<Option<[f32; 4]>, GltfError> {
+            Ok(Some([1.0, 0.0, 0.0, 1.0]))
+        }
+    }
+
+    fn chunk(glb: &[u8], offset: usize) -> (u32, &[u8]) {
+        let word = |offset: usize| {
+            let mut bytes = [0u8; 4];
+            bytes.copy_from_slice(&glb[offset..offset + 4]);
+            u32::from_le_bytes(bytes)
+        };
+        let len = word(offset) as usize;
+        (word(offset + 4), &glb[offset + 8..offset + 8 + len])
+    }
+
+    #[test]
+    fn encode_flat_buffer() {
+        let buffer: MeshBuffer<Flat3<u32>, E3> = Cube::new()
+            .polygons::<Position<E3>>() // 12 triangles, 8 vertices.
+            .triangulate()
+            .collect();
+        let mut glb = Vec::new();
+        let n = buffer
+            .to_glb(PositionEncoding::<E3>::default(), &mut glb)
+            .unwrap();
+
+        assert_eq!(n, glb.len());
+        assert_eq!(0, n % 4);
+        assert_eq!(b"glTF", &glb[..4]);
+        let (kind, json) = chunk(&glb, 12);
+        let json = String::from_utf8(json.to_vec()).unwrap();
+        assert_eq!(0x4e4f_534a, kind);
+        assert!(json.contains(r#""componentType":5125,"count":36"#));
+        assert!(json.contains(r#""min":[-0.5,-0.5,-0.5],"max":[0.5,0.5,0.5]"#));
+        assert!(!json.contains("NORMAL"));
+        let (kind, data) = chunk(&glb, 20 + (json.len()));
+        assert_eq!(0x004e_4942, kind);
+        assert_eq!((36 * 4) + (8 * 12), data.len());
+    }
+
+    #[test]
+    fn encode_structured_buffer_with_attributes() {
+        let buffer: MeshBuffer3<usize, E3> = Cube::new()
+            .polygons::<Position<E3>>() // 12 triangles, 8 vertices.
+            .triangulate()
+            .collect();
+        let mut glb = Vec::new();
+        buffer.to_glb(ColorEncoding, &mut glb).unwrap();
+
+        let (_, json) = chunk(&glb, 12);
+        let json = String::from_utf8(json.to_vec()).unwrap();
+        assert!(json.contains(r#""componentType":5123,"count":36"#));
+        assert!(json.contains(r#""NORMAL":2"#));
+        assert!(json.contains(r#""COLOR_0":3"#));
+    }
+
+    #[test]
+    fn encode_empty_buffer_error() {
+        let buffer = MeshBuffer3::<usize, E3>::default();
+        assert!(matches!(
+            buffer.to_glb(PositionEncoding::<E3>::default(), Vec::new()),
+            Err(GltfError::Empty)
+        ));
+    }
+}
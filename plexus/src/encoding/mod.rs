@@ -5,18 +5,28 @@
 //! support is enabled, the `ply` module is exposed. The following table
 //! summarizes the encodings supported by Plexus:
 //!
-//! | Feature        | Default | Encoding | Read | Write |
-//! |----------------|---------|----------|------|-------|
-//! | `encoding-ply` | No      | [PLY]    | Yes  | No    |
+//! | Feature         | Default | Encoding | Read | Write |
+//! |-----------------|---------|----------|------|-------|
+//! | `encoding-gltf` | No      | [glTF]   | No   | Yes   |
+//! | `encoding-obj`  | No      | [OBJ]    | Yes  | Yes   |
+//! | `encoding-ply`  | No      | [PLY]    | Yes  | Yes   |
+//! | `encoding-stl`  | No      | [STL]    | Yes  | Yes   |
 //!
 //! This module provides traits used by all encodings. These traits describe the
 //! outputs and inputs of decoders and encoders, respectively. Generally, these
 //! traits should **not** be used directly. Instead, prefer the conversion
-//! traits exposed for specific encodings, such as `FromPly` when using [PLY].
+//! traits exposed for specific encodings, such as `FromPly` and `ToPly` when
+//! using [PLY].
 //!
+//! [glTF]: https://www.khronos.org/gltf/
+//! [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
 //! [PLY]: https://en.wikipedia.org/wiki/ply_(file_format)
+//! [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
 
+pub mod gltf;
+pub mod obj;
 pub mod ply;
+pub mod stl;
 
 use std::fmt::Debug;
 
@@ -31,9 +41,29 @@ pub trait FaceDecoder {
     type Face;
 }
 
-// TODO: This trait is a bit limiting. Consider implementing more specific
-//       traits like `FromPly` directly. This could allow more specific
-//       features to be supported, such as edge geometry for `MeshGraph`s.
+/// Decoder of arc data.
+///
+/// Each arc is identified by the indices of its source and destination
+/// vertices, in that order, as given by the corresponding [`VertexDecoder`].
+///
+/// [`VertexDecoder`]: crate::encoding::VertexDecoder
+pub trait ArcDecoder {
+    type Output: IntoIterator<Item = ((usize, usize), Self::Arc)>;
+    type Arc;
+}
+
+/// Decoder of edge data.
+///
+/// Each edge is identified by the indices of the vertices that it connects, as
+/// given by the corresponding [`VertexDecoder`]. The order of these indices is
+/// not significant.
+///
+/// [`VertexDecoder`]: crate::encoding::VertexDecoder
+pub trait EdgeDecoder {
+    type Output: IntoIterator<Item = ((usize, usize), Self::Edge)>;
+    type Edge;
+}
+
 pub trait FromEncoding<E>: Sized
 where
     E: FaceDecoder + VertexDecoder,
@@ -45,3 +75,25 @@ where
         faces: <E as FaceDecoder>::Output,
     ) -> Result<Self, Self::Error>;
 }
+
+/// Conversion from encodings that also describe arcs and edges.
+///
+/// Unlike [`FromEncoding`], this trait supports data associated with arcs and
+/// edges, such as crease weights or seams. Arcs and edges are determined by
+/// the topology of faces; [`ArcDecoder`] and [`EdgeDecoder`] only provide
+/// their data. Arcs and edges that are not decoded are given default data.
+///
+/// [`ArcDecoder`]: crate::encoding::ArcDecoder
+/// [`EdgeDecoder`]: crate::encoding::EdgeDecoder
+/// [`FromEncoding`]: crate::encoding::FromEncoding
+pub trait FromEdgeEncoding<E>: FromEncoding<E>
+where
+    E: ArcDecoder + EdgeDecoder + FaceDecoder + VertexDecoder,
+{
+    fn from_edge_encoding(
+        vertices: <E as VertexDecoder>::Output,
+        faces: <E as FaceDecoder>::Output,
+        arcs: <E as ArcDecoder>::Output,
+        edges: <E as EdgeDecoder>::Output,
+    ) -> Result<Self, Self::Error>;
+}
@@ -0,0 +1,1119 @@
+//! [Wavefront OBJ][OBJ] encoding.
+//!
+//! This module provides support for the [OBJ] format via the [`FromObj`] and
+//! [`ToObj`] traits. These traits can be used with a decoder and encoder to
+//! read and write mesh data structures to and from the [OBJ] format.
+//!
+//! Vertex positions (`v`), texture coordinates (`vt`), normals (`vn`), and
+//! polygonal faces (`f`) are supported. Faces may use negative (relative)
+//! indices. Object (`o`) and group (`g`) statements are tracked for each face
+//! and can be decoded as face data. Other statements, such as materials and
+//! smoothing groups, are ignored.
+//!
+//! [`Vertex`] can be used as the [`GraphData`] of a [`MeshGraph`], in which
+//! case faces store their [`Group`] and groups are preserved when the graph is
+//! written with a [`VertexEncoding`]. Group names are not stored in a graph;
+//! use [`VertexEncoding::with_names`] with the names of the decoded [`Obj`] to
+//! write them back.
+//!
+//! # Examples
+//!
+//! Reading an [OBJ] file into a [`MeshGraph`]:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::encoding::obj::{FromObj, PositionEncoding};
+//! use plexus::graph::MeshGraph;
+//! use std::io::Read;
+//!
+//! type E3 = Point3<f64>;
+//!
+//! // Read from a file, network, etc.
+//! fn read() -> impl Read {
+//!     // ...
+//!     # let obj: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+//!     # obj
+//! }
+//!
+//! let encoding = PositionEncoding::<E3>::default();
+//! let (graph, _) = MeshGraph::<E3>::from_obj(encoding, read()).unwrap();
+//! ```
+//!
+//! [obj]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+//!
+//! [`FromObj`]: crate::encoding::obj::FromObj
+//! [`Group`]: crate::encoding::obj::Group
+//! [`Obj`]: crate::encoding::obj::Obj
+//! [`ToObj`]: crate::encoding::obj::ToObj
+//! [`Vertex`]: crate::encoding::obj::Vertex
+//! [`VertexEncoding`]: crate::encoding::obj::VertexEncoding
+//! [`VertexEncoding::with_names`]: crate::encoding::obj::VertexEncoding::with_names
+//! [`GraphData`]: crate::graph::GraphData
+//! [`MeshGraph`]: crate::graph::MeshGraph
+
+#![cfg(feature = "encoding-obj")]
+
+use num::cast;
+use num::{Integer, NumCast};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::marker::PhantomData;
+use std::str::FromStr;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use theon::{AsPosition, AsPositionMut};
+use thiserror::Error;
+use typenum::{NonZero, Unsigned, U2, U3};
+
+use crate::buffer::{BufferError, MeshBuffer};
+use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::geometry::UnitGeometry;
+use crate::graph::{ClosedView as _, GraphData, GraphError, MeshGraph};
+use crate::index::{Flat, Grouping};
+use crate::primitive::Polygonal;
+
+/// Errors concerning the [OBJ] encoding.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+#[derive(Debug, Error)]
+pub enum ObjError {
+    /// A statement could not be parsed.
+    #[error("malformed statement on line {line}")]
+    Malformed {
+        /// The line on which the malformed statement begins.
+        line: usize,
+    },
+    /// An index in a face statement is zero or out of bounds.
+    #[error("index out of bounds")]
+    IndexOutOfBounds,
+    /// Data could not be converted to or from its encoding.
+    #[error("failed to convert data")]
+    Encoding,
+    #[error("failed to build mesh buffer")]
+    Buffer(#[from] BufferError),
+    #[error("failed to build mesh graph")]
+    Graph(#[from] GraphError),
+    #[error("failed to read or write data")]
+    Io(#[from] io::Error),
+}
+
+/// Vertex of a face.
+///
+/// Corners index the position, texture, and normal records of an [`Obj`].
+/// Indices are zero-based and absolute.
+///
+/// [`Obj`]: crate::encoding::obj::Obj
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Corner {
+    pub position: usize,
+    pub texture: Option<usize>,
+    pub normal: Option<usize>,
+}
+
+/// Face statement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Face {
+    pub corners: SmallVec<[Corner; 4]>,
+    pub group: Group,
+}
+
+/// Object and group of a face.
+///
+/// The `object` and `group` fields index the names in [`Obj::objects`] and
+/// [`Obj::groups`], respectively.
+///
+/// [`Obj::groups`]: crate::encoding::obj::Obj::groups
+/// [`Obj::objects`]: crate::encoding::obj::Obj::objects
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Group {
+    pub object: Option<usize>,
+    pub group: Option<usize>,
+}
+
+impl UnitGeometry for Group {}
+
+/// Vertex data decoded from the records referenced by a [`Corner`].
+///
+/// [`Corner`]: crate::encoding::obj::Corner
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vertex<T> {
+    pub position: T,
+    pub texture: Option<(f64, f64)>,
+    pub normal: Option<(f64, f64, f64)>,
+}
+
+impl<T> AsPosition for Vertex<T>
+where
+    T: EuclideanSpace,
+{
+    type Position = T;
+
+    fn as_position(&self) -> &Self::Position {
+        &self.position
+    }
+}
+
+impl<T> AsPositionMut for Vertex<T>
+where
+    T: EuclideanSpace,
+{
+    fn as_position_mut(&mut self) -> &mut Self::Position {
+        &mut self.position
+    }
+}
+
+impl<T> GraphData for Vertex<T>
+where
+    T: Copy,
+{
+    type Vertex = Self;
+    type Arc = ();
+    type Edge = ();
+    type Face = Group;
+}
+
+/// Statements read from or written to the [OBJ] encoding.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Obj {
+    pub positions: Vec<[f64; 3]>,
+    pub textures: Vec<[f64; 2]>,
+    pub normals: Vec<[f64; 3]>,
+    pub faces: Vec<Face>,
+    /// Names given by object statements.
+    pub objects: Vec<String>,
+    /// Names given by group statements. A group statement may give more than
+    /// one name.
+    pub groups: Vec<Vec<String>>,
+}
+
+impl Obj {
+    pub fn parse<R>(read: R) -> Result<Self, ObjError>
+    where
+        R: Read,
+    {
+        let mut obj = Obj::default();
+        let mut group = Group::default();
+        let mut statement = String::new();
+        let mut start = 0;
+        for (n, line) in BufReader::new(read).lines().enumerate() {
+            let line = line?;
+            if statement.is_empty() {
+                start = n + 1;
+            }
+            // Statements may span lines via a trailing backslash.
+            let line = line.trim_end();
+            if line.ends_with('\\') {
+                statement.push_str(line.trim_end_matches('\\'));
+                statement.push(' ');
+                continue;
+            }
+            statement.push_str(line);
+            obj.parse_statement(&statement, &mut group)
+                .map_err(malformed(start))?;
+            statement.clear();
+        }
+        if !statement.is_empty() {
+            obj.parse_statement(&statement, &mut group)
+                .map_err(malformed(start))?;
+        }
+        obj.validate()?;
+        Ok(obj)
+    }
+
+    pub fn write<W>(&self, mut write: W) -> Result<usize, ObjError>
+    where
+        W: Write,
+    {
+        let mut n = 0;
+        let mut line = |line: String| -> Result<(), ObjError> {
+            write.write_all(line.as_bytes())?;
+            write.write_all(b"\n")?;
+            n += line.len() + 1;
+            Ok(())
+        };
+        for [x, y, z] in &self.positions {
+            line(format!("v {} {} {}", x, y, z))?;
+        }
+        for [u, v] in &self.textures {
+            line(format!("vt {} {}", u, v))?;
+        }
+        for [x, y, z] in &self.normals {
+            line(format!("vn {} {} {}", x, y, z))?;
+        }
+        let mut group = Group::default();
+        for face in &self.faces {
+            if face.group.object != group.object {
+                if let Some(object) = face.group.object {
+                    let name = self.objects.get(object).ok_or(ObjError::IndexOutOfBounds)?;
+                    line(format!("o {}", name))?;
+                }
+            }
+            if face.group.group != group.group {
+                match face.group.group {
+                    Some(index) => {
+                        let names = self.groups.get(index).ok_or(ObjError::IndexOutOfBounds)?;
+                        line(format!("g {}", names.join(" ")))?;
+                    }
+                    None => {
+                        line("g".to_owned())?;
+                    }
+                }
+            }
+            group = face.group;
+            let corners = face
+                .corners
+                .iter()
+                .map(|corner| match (corner.texture, corner.normal) {
+                    (None, None) => format!("{}", corner.position + 1),
+                    (Some(texture), None) => format!("{}/{}", corner.position + 1, texture + 1),
+                    (None, Some(normal)) => format!("{}//{}", corner.position + 1, normal + 1),
+                    (Some(texture), Some(normal)) => {
+                        format!("{}/{}/{}", corner.position + 1, texture + 1, normal + 1)
+                    }
+                })
+                .collect::<Vec<_>>();
+            line(format!("f {}", corners.join(" ")))?;
+        }
+        Ok(n)
+    }
+
+    fn parse_statement(&mut self, statement: &str, group: &mut Group) -> Result<(), ObjError> {
+        let statement = match statement.find('#') {
+            Some(index) => &statement[..index],
+            _ => statement,
+        };
+        let mut tokens = statement.split_whitespace();
+        let keyword = match tokens.next() {
+            Some(keyword) => keyword,
+            _ => {
+                return Ok(());
+            }
+        };
+        match keyword {
+            "v" => {
+                let values = parse_scalars(tokens)?;
+                let position = match values.as_slice() {
+                    [x, y] => [*x, *y, 0.0],
+                    [x, y, z] | [x, y, z, _] => [*x, *y, *z],
+                    _ => {
+                        return Err(ObjError::Encoding);
+                    }
+                };
+                self.positions.push(position);
+            }
+            "vt" => {
+                let values = parse_scalars(tokens)?;
+                let texture = match values.as_slice() {
+                    [u] => [*u, 0.0],
+                    [u, v] | [u, v, _] => [*u, *v],
+                    _ => {
+                        return Err(ObjError::Encoding);
+                    }
+                };
+                self.textures.push(texture);
+            }
+            "vn" => {
+                let values = parse_scalars(tokens)?;
+                let normal = match values.as_slice() {
+                    [x, y, z] => [*x, *y, *z],
+                    _ => {
+                        return Err(ObjError::Encoding);
+                    }
+                };
+                self.normals.push(normal);
+            }
+            "f" => {
+                let corners = tokens
+                    .map(|token| self.parse_corner(token))
+                    .collect::<Result<SmallVec<[_; 4]>, _>>()?;
+                if corners.len() < 3 {
+                    return Err(ObjError::Encoding);
+                }
+                self.faces.push(Face {
+                    corners,
+                    group: *group,
+                });
+            }
+            "o" => {
+                let name = tokens.collect::<Vec<_>>().join(" ");
+                group.object = Some(index_of_or_insert(&mut self.objects, name));
+            }
+            "g" => {
+                let names = tokens.map(|name| name.to_owned()).collect::<Vec<_>>();
+                group.group = if names.is_empty() {
+                    None
+                }
+                else {
+                    Some(index_of_or_insert(&mut self.groups, names))
+                };
+            }
+            // Ignore unsupported statements.
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn parse_corner(&self, token: &str) -> Result<Corner, ObjError> {
+        let mut indices = token.split('/');
+        let position = indices
+            .next()
+            .ok_or(ObjError::Encoding)
+            .and_then(|index| parse_index(index, self.positions.len()))?;
+        let texture = match indices.next() {
+            Some("") | None => None,
+            Some(index) => Some(parse_index(index, self.textures.len())?),
+        };
+        let normal = match indices.next() {
+            Some("") | None => None,
+            Some(index) => Some(parse_index(index, self.normals.len())?),
+        };
+        if indices.next().is_some() {
+            Err(ObjError::Encoding)
+        }
+        else {
+            Ok(Corner {
+                position,
+                texture,
+                normal,
+            })
+        }
+    }
+
+    fn validate(&self) -> Result<(), ObjError> {
+        let is_out_of_bounds = self
+            .faces
+            .iter()
+            .flat_map(|face| &face.corners)
+            .any(|corner| {
+                corner.position >= self.positions.len()
+                    || corner
+                        .texture
+                        .map_or(false, |index| index >= self.textures.len())
+                    || corner
+                        .normal
+                        .map_or(false, |index| index >= self.normals.len())
+            });
+        if is_out_of_bounds {
+            Err(ObjError::IndexOutOfBounds)
+        }
+        else {
+            Ok(())
+        }
+    }
+}
+
+pub trait RecordDecoder: FaceDecoder + VertexDecoder {
+    fn decode_records(
+        &self,
+        obj: &Obj,
+    ) -> Result<
+        (
+            <Self as VertexDecoder>::Output,
+            <Self as FaceDecoder>::Output,
+        ),
+        ObjError,
+    >;
+}
+
+pub trait RecordEncoder<V, F> {
+    /// Encodes the records of a vertex.
+    ///
+    /// Returns a corner that indexes the encoded records.
+    fn encode_vertex(&self, obj: &mut Obj, vertex: &V) -> Result<Corner, ObjError>;
+
+    fn encode_face(
+        &self,
+        obj: &mut Obj,
+        corners: SmallVec<[Corner; 4]>,
+        face: &F,
+    ) -> Result<(), ObjError>;
+}
+
+/// Conversion from [OBJ].
+///
+/// Face data is decoded only if both the decoder and the target support it.
+/// For example, a [`MeshGraph`] of [`Vertex`] data decoded with a
+/// [`VertexEncoding`] stores the [`Group`] of each face, but a [`MeshGraph`]
+/// with no face data or a [`MeshBuffer`] discards groups.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+///
+/// [`MeshBuffer`]: crate::buffer::MeshBuffer
+/// [`Group`]: crate::encoding::obj::Group
+/// [`Vertex`]: crate::encoding::obj::Vertex
+/// [`VertexEncoding`]: crate::encoding::obj::VertexEncoding
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub trait FromObj<E>: Sized {
+    /// Reads [OBJ] data using the given decoder.
+    ///
+    /// Returns the decoded mesh and the parsed [`Obj`] statements, which
+    /// include the names of objects and groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data cannot be read, parsed, or decoded.
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    ///
+    /// [`Obj`]: crate::encoding::obj::Obj
+    fn from_obj<R>(decoder: E, read: R) -> Result<(Self, Obj), ObjError>
+    where
+        R: Read;
+}
+
+impl<T, E> FromObj<E> for T
+where
+    T: FromEncoding<E>,
+    ObjError: From<<T as FromEncoding<E>>::Error>,
+    E: RecordDecoder,
+{
+    fn from_obj<R>(decoder: E, read: R) -> Result<(Self, Obj), ObjError>
+    where
+        R: Read,
+    {
+        let obj = Obj::parse(read)?;
+        let (vertices, faces) = decoder.decode_records(&obj)?;
+        let mesh = T::from_encoding(vertices, faces)?;
+        Ok((mesh, obj))
+    }
+}
+
+/// Conversion into [OBJ].
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+pub trait ToObj<E> {
+    /// Writes [OBJ] data using the given encoder.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if data cannot be encoded or written.
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    fn to_obj<W>(&self, encoder: E, write: W) -> Result<usize, ObjError>
+    where
+        W: Write;
+}
+
+impl<E, A, N, G> ToObj<E> for MeshBuffer<Flat<A, N>, G>
+where
+    E: RecordEncoder<G, ()>,
+    A: NonZero + Unsigned,
+    N: Copy + Integer + NumCast + num::Unsigned,
+{
+    fn to_obj<W>(&self, encoder: E, write: W) -> Result<usize, ObjError>
+    where
+        W: Write,
+    {
+        let mut obj = Obj::default();
+        let corners = self
+            .as_vertex_slice()
+            .iter()
+            .map(|vertex| encoder.encode_vertex(&mut obj, vertex))
+            .collect::<Result<Vec<_>, _>>()?;
+        for chunk in self.as_index_slice().chunks(A::USIZE) {
+            let chunk = chunk
+                .iter()
+                .map(|index| {
+                    <usize as NumCast>::from(*index)
+                        .and_then(|index| corners.get(index).cloned())
+                        .ok_or(ObjError::IndexOutOfBounds)
+                })
+                .collect::<Result<_, _>>()?;
+            encoder.encode_face(&mut obj, chunk, &())?;
+        }
+        obj.write(write)
+    }
+}
+
+impl<E, P, G> ToObj<E> for MeshBuffer<P, G>
+where
+    E: RecordEncoder<G, ()>,
+    P: Grouping<Group = P> + Polygonal,
+    P::Vertex: Copy + Integer + NumCast + num::Unsigned,
+{
+    fn to_obj<W>(&self, encoder: E, write: W) -> Result<usize, ObjError>
+    where
+        W: Write,
+    {
+        let mut obj = Obj::default();
+        let corners = self
+            .as_vertex_slice()
+            .iter()
+            .map(|vertex| encoder.encode_vertex(&mut obj, vertex))
+            .collect::<Result<Vec<_>, _>>()?;
+        for polygon in self.as_index_slice() {
+            let polygon = polygon
+                .as_ref()
+                .iter()
+                .map(|index| {
+                    <usize as NumCast>::from(*index)
+                        .and_then(|index| corners.get(index).cloned())
+                        .ok_or(ObjError::IndexOutOfBounds)
+                })
+                .collect::<Result<_, _>>()?;
+            encoder.encode_face(&mut obj, polygon, &())?;
+        }
+        obj.write(write)
+    }
+}
+
+impl<E, G> ToObj<E> for MeshGraph<G>
+where
+    E: RecordEncoder<G::Vertex, G::Face>,
+    G: GraphData,
+{
+    fn to_obj<W>(&self, encoder: E, write: W) -> Result<usize, ObjError>
+    where
+        W: Write,
+    {
+        let mut obj = Obj::default();
+        let mut corners = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            corners.insert(vertex.key(), encoder.encode_vertex(&mut obj, &vertex.data)?);
+        }
+        for face in self.faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| corners[&vertex.key()])
+                .collect();
+            encoder.encode_face(&mut obj, perimeter, &face.data)?;
+        }
+        obj.write(write)
+    }
+}
+
+pub trait DecodePosition<N>: FiniteDimensional<N = N> + Sized
+where
+    N: NonZero + Unsigned,
+{
+    fn decode_position(position: &[f64; 3]) -> Result<Self, ObjError>;
+}
+
+impl<T> DecodePosition<U2> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    fn decode_position(position: &[f64; 3]) -> Result<Self, ObjError> {
+        let [x, y, _] = *position;
+        Ok(EuclideanSpace::from_xy(
+            num_cast_scalar(x)?,
+            num_cast_scalar(y)?,
+        ))
+    }
+}
+
+impl<T> DecodePosition<U3> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn decode_position(position: &[f64; 3]) -> Result<Self, ObjError> {
+        let [x, y, z] = *position;
+        Ok(EuclideanSpace::from_xyz(
+            num_cast_scalar(x)?,
+            num_cast_scalar(y)?,
+            num_cast_scalar(z)?,
+        ))
+    }
+}
+
+pub trait EncodePosition<N>: FiniteDimensional<N = N> + Sized
+where
+    N: NonZero + Unsigned,
+{
+    fn encode_position(&self) -> Result<[f64; 3], ObjError>;
+}
+
+impl<T> EncodePosition<U2> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    fn encode_position(&self) -> Result<[f64; 3], ObjError> {
+        let (x, y) = self.into_xy();
+        Ok([num_cast_scalar(x)?, num_cast_scalar(y)?, 0.0])
+    }
+}
+
+impl<T> EncodePosition<U3> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn encode_position(&self) -> Result<[f64; 3], ObjError> {
+        let (x, y, z) = self.into_xyz();
+        Ok([
+            num_cast_scalar(x)?,
+            num_cast_scalar(y)?,
+            num_cast_scalar(z)?,
+        ])
+    }
+}
+
+/// Encoding of vertex positions.
+///
+/// When decoding, the vertex data is read from position records and faces
+/// index these positions directly. Texture and normal records and groups are
+/// ignored. When encoding, only position records are written.
+pub struct PositionEncoding<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for PositionEncoding<T> {
+    fn default() -> Self {
+        PositionEncoding {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> FaceDecoder for PositionEncoding<T> {
+    type Output = Vec<(Self::Index, Self::Face)>;
+    type Index = SmallVec<[usize; 4]>;
+    type Face = ();
+}
+
+impl<T> VertexDecoder for PositionEncoding<T> {
+    type Output = Vec<Self::Vertex>;
+    type Vertex = T;
+}
+
+impl<T, N> RecordDecoder for PositionEncoding<T>
+where
+    T: DecodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+{
+    fn decode_records(
+        &self,
+        obj: &Obj,
+    ) -> Result<
+        (
+            <Self as VertexDecoder>::Output,
+            <Self as FaceDecoder>::Output,
+        ),
+        ObjError,
+    > {
+        let vertices = obj
+            .positions
+            .iter()
+            .map(T::decode_position)
+            .collect::<Result<_, _>>()?;
+        let faces = obj
+            .faces
+            .iter()
+            .map(|face| {
+                let indices = face.corners.iter().map(|corner| corner.position).collect();
+                (indices, ())
+            })
+            .collect();
+        Ok((vertices, faces))
+    }
+}
+
+impl<T, N, V, F> RecordEncoder<V, F> for PositionEncoding<T>
+where
+    T: EncodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+    V: AsPosition<Position = T>,
+{
+    fn encode_vertex(&self, obj: &mut Obj, vertex: &V) -> Result<Corner, ObjError> {
+        obj.positions.push(vertex.as_position().encode_position()?);
+        Ok(Corner {
+            position: obj.positions.len() - 1,
+            texture: None,
+            normal: None,
+        })
+    }
+
+    fn encode_face(
+        &self,
+        obj: &mut Obj,
+        corners: SmallVec<[Corner; 4]>,
+        _: &F,
+    ) -> Result<(), ObjError> {
+        obj.faces.push(Face {
+            corners,
+            group: Group::default(),
+        });
+        Ok(())
+    }
+}
+
+/// Encoding of vertex positions, texture coordinates, normals, and groups.
+///
+/// When decoding, a [`Vertex`] is read for each distinct combination of
+/// position, texture, and normal records referenced by faces and faces are
+/// read as [`Group`]s. Records that are not referenced by any face are
+/// ignored. When encoding, these same records are written for each
+/// [`Vertex`] and object and group statements are written for each
+/// [`Group`].
+///
+/// [`Group`]: crate::encoding::obj::Group
+/// [`Vertex`]: crate::encoding::obj::Vertex
+pub struct VertexEncoding<T> {
+    objects: Vec<String>,
+    groups: Vec<Vec<String>>,
+    phantom: PhantomData<T>,
+}
+
+impl<T> VertexEncoding<T> {
+    /// Creates an encoding that writes the given names for objects and groups.
+    ///
+    /// The names of objects and groups that are not given are derived from
+    /// their indices.
+    pub fn with_names(objects: Vec<String>, groups: Vec<Vec<String>>) -> Self {
+        VertexEncoding {
+            objects,
+            groups,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for VertexEncoding<T> {
+    fn default() -> Self {
+        VertexEncoding::with_names(Vec::new(), Vec::new())
+    }
+}
+
+impl<T> FaceDecoder for VertexEncoding<T> {
+    type Output = Vec<(Self::Index, Self::Face)>;
+    type Index = SmallVec<[usize; 4]>;
+    type Face = Group;
+}
+
+impl<T> VertexDecoder for VertexEncoding<T> {
+    type Output = Vec<Self::Vertex>;
+    type Vertex = Vertex<T>;
+}
+
+impl<T, N> RecordDecoder for VertexEncoding<T>
+where
+    T: DecodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+{
+    fn decode_records(
+        &self,
+        obj: &Obj,
+    ) -> Result<
+        (
+            <Self as VertexDecoder>::Output,
+            <Self as FaceDecoder>::Output,
+        ),
+        ObjError,
+    > {
+        let mut indices = HashMap::new();
+        let mut vertices = Vec::new();
+        let mut faces = Vec::with_capacity(obj.faces.len());
+        for face in &obj.faces {
+            let perimeter = face
+                .corners
+                .iter()
+                .map(|corner| {
+                    if let Some(index) = indices.get(corner) {
+                        return Ok(*index);
+                    }
+                    let vertex = Vertex {
+                        position: T::decode_position(&obj.positions[corner.position])?,
+                        texture: corner.texture.map(|index| {
+                            let [u, v] = obj.textures[index];
+                            (u, v)
+                        }),
+                        normal: corner.normal.map(|index| {
+                            let [x, y, z] = obj.normals[index];
+                            (x, y, z)
+                        }),
+                    };
+                    vertices.push(vertex);
+                    indices.insert(*corner, vertices.len() - 1);
+                    Ok(vertices.len() - 1)
+                })
+                .collect::<Result<_, ObjError>>()?;
+            faces.push((perimeter, face.group));
+        }
+        Ok((vertices, faces))
+    }
+}
+
+impl<T, N, F> RecordEncoder<Vertex<T>, F> for VertexEncoding<T>
+where
+    T: EncodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+    F: Copy + Into<Group>,
+{
+    fn encode_vertex(&self, obj: &mut Obj, vertex: &Vertex<T>) -> Result<Corner, ObjError> {
+        obj.positions.push(vertex.position.encode_position()?);
+        let position = obj.positions.len() - 1;
+        let texture = vertex.texture.map(|(u, v)| {
+            obj.textures.push([u, v]);
+            obj.textures.len() - 1
+        });
+        let normal = vertex.normal.map(|(x, y, z)| {
+            obj.normals.push([x, y, z]);
+            obj.normals.len() - 1
+        });
+        Ok(Corner {
+            position,
+            texture,
+            normal,
+        })
+    }
+
+    fn encode_face(
+        &self,
+        obj: &mut Obj,
+        corners: SmallVec<[Corner; 4]>,
+        face: &F,
+    ) -> Result<(), ObjError> {
+        let group: Group = (*face).into();
+        if let Some(object) = group.object {
+            while obj.objects.len() <= object {
+                let index = obj.objects.len();
+                obj.objects.push(
+                    self.objects
+                        .get(index)
+                        .cloned()
+                        .unwrap_or_else(|| format!("object{}", index)),
+                );
+            }
+        }
+        if let Some(index) = group.group {
+            while obj.groups.len() <= index {
+                let index = obj.groups.len();
+                obj.groups.push(
+                    self.groups
+                        .get(index)
+                        .cloned()
+                        .unwrap_or_else(|| vec![format!("group{}", index)]),
+                );
+            }
+        }
+        obj.faces.push(Face { corners, group });
+        Ok(())
+    }
+}
+
+impl From<()> for Group {
+    fn from(_: ()) -> Self {
+        Group::default()
+    }
+}
+
+fn parse_scalars<'a, I>(tokens: I) -> Result<SmallVec<[f64; 4]>, ObjError>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    tokens
+        .into_iter()
+        .map(|token| f64::from_str(token).map_err(|_| ObjError::Encoding))
+        .collect()
+}
+
+fn malformed(line: usize) -> impl Fn(ObjError) -> ObjError {
+    move |error| match error {
+        ObjError::Encoding => ObjError::Malformed { line },
+        _ => error,
+    }
+}
+
+fn parse_index(token: &str, len: usize) -> Result<usize, ObjError> {
+    let index = isize::from_str(token).map_err(|_| ObjError::Encoding)?;
+    if index > 0 {
+        Ok(index as usize - 1)
+    }
+    else if index < 0 {
+        // Negative indices are relative to the records read so far.
+        let offset = (-index) as usize;
+        if offset > len {
+            Err(ObjError::IndexOutOfBounds)
+        }
+        else {
+            Ok(len - offset)
+        }
+    }
+    else {
+        Err(ObjError::IndexOutOfBounds)
+    }
+}
+
+fn index_of_or_insert<T>(items: &mut Vec<T>, item: T) -> usize
+where
+    T: PartialEq,
+{
+    match items.iter().position(|other| *other == item) {
+        Some(index) => index,
+        _ => {
+            items.push(item);
+            items.len() - 1
+        }
+    }
+}
+
+fn num_cast_scalar<T, U>(value: T) -> Result<U, ObjError>
+where
+    T: NumCast,
+    U: NumCast,
+{
+    cast::cast(value).ok_or(ObjError::Encoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::buffer::MeshBuffer;
+    use crate::encoding::obj::{FromObj, PositionEncoding, ToObj, Vertex, VertexEncoding};
+    use crate::graph::MeshGraph;
+    use crate::primitive::Tetragon;
+
+    type E3 = Point3<f64>;
+
+    const CUBE: &[u8] = b"\
+        # Cube.\n\
+        o cube\n\
+        v 0 0 0\n\
+        v 0 0 1\n\
+        v 0 1 1\n\
+        v 0 1 0\n\
+        v 1 0 0\n\
+        v 1 0 1\n\
+        v 1 1 1\n\
+        v 1 1 0\n\
+        vt 0 0\n\
+        vn 0 0 1\n\
+        g left\n\
+        f 1 2 3 4\n\
+        g right\n\
+        f 8/1/1 7/1/1 6/1/1 5/1/1\n\
+        g sides\n\
+        f -8 -4 -3 -7\n\
+        f 2 6 7 3\n\
+        f 3 7 8 4\n\
+        f 1 4 8 5\n\
+    ";
+
+    #[test]
+    fn decode_into_buffer() {
+        let buffer =
+            MeshBuffer::<Tetragon<usize>, E3>::from_obj(PositionEncoding::<E3>::default(), CUBE)
+                .unwrap()
+                .0;
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        assert_eq!(6, buffer.as_index_slice().len());
+    }
+
+    #[test]
+    fn decode_into_graph() {
+        let (graph, obj) =
+            MeshGraph::<E3>::from_obj(PositionEncoding::<E3>::default(), CUBE).unwrap();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+        assert_eq!(vec!["cube".to_owned()], obj.objects);
+        assert_eq!(3, obj.groups.len());
+    }
+
+    #[test]
+    fn decode_relative_indices() {
+        let obj: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf -3 -2 -1\n";
+        let graph = MeshGraph::<E3>::from_obj(PositionEncoding::<E3>::default(), obj)
+            .unwrap()
+            .0;
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+    }
+
+    #[test]
+    fn decode_out_of_bounds_error() {
+        let obj: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n";
+        assert!(MeshGraph::<E3>::from_obj(PositionEncoding::<E3>::default(), obj).is_err());
+    }
+
+    #[test]
+    fn encode_vertex_attributes() {
+        let obj: &[u8] = b"\
+            v 0 0 0\n\
+            v 1 0 0\n\
+            v 0 1 0\n\
+            vt 0 0\n\
+            vt 1 0\n\
+            vt 0 1\n\
+            vn 0 0 1\n\
+            g triangle\n\
+            f 1/1/1 2/2/1 3/3/1\n\
+        ";
+        let graph = MeshGraph::<Vertex<E3>>::from_obj(VertexEncoding::<E3>::default(), obj)
+            .unwrap()
+            .0;
+        assert_eq!(3, graph.vertex_count());
+        for vertex in graph.vertices() {
+            assert!(vertex.data.texture.is_some());
+            assert_eq!(Some((0.0, 0.0, 1.0)), vertex.data.normal);
+        }
+
+        let mut obj = Vec::new();
+        graph
+            .to_obj(VertexEncoding::<E3>::default(), &mut obj)
+            .unwrap();
+        let (graph, obj) =
+            MeshGraph::<Vertex<E3>>::from_obj(VertexEncoding::<E3>::default(), obj.as_slice())
+                .unwrap();
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(3, obj.textures.len());
+        assert_eq!(3, obj.normals.len());
+    }
+
+    #[test]
+    fn encode_graph_groups() {
+        let (graph, obj) =
+            MeshGraph::<Vertex<E3>>::from_obj(VertexEncoding::<E3>::default(), CUBE).unwrap();
+        let encoding = VertexEncoding::<E3>::with_names(obj.objects.clone(), obj.groups.clone());
+        let mut buffer = Vec::new();
+        graph.to_obj(encoding, &mut buffer).unwrap();
+        let (graph, obj) =
+            MeshGraph::<Vertex<E3>>::from_obj(VertexEncoding::<E3>::default(), buffer.as_slice())
+                .unwrap();
+        assert_eq!(6, graph.face_count());
+        assert_eq!(vec!["cube".to_owned()], obj.objects);
+        let count = |name: &str| {
+            graph
+                .faces()
+                .filter(|face| {
+                    face.data.object == Some(0)
+                        && face
+                            .data
+                            .group
+                            .map(|index| obj.groups[index] == [name])
+                            .unwrap_or(false)
+                })
+                .count()
+        };
+        assert_eq!(1, count("left"));
+        assert_eq!(1, count("right"));
+        assert_eq!(4, count("sides"));
+    }
+
+    #[test]
+    fn encode_buffer() {
+        let buffer =
+            MeshBuffer::<Tetragon<usize>, E3>::from_obj(PositionEncoding::<E3>::default(), CUBE)
+                .unwrap()
+                .0;
+        let mut obj = Vec::new();
+        buffer
+            .to_obj(PositionEncoding::<E3>::default(), &mut obj)
+            .unwrap();
+        let buffer = MeshBuffer::<Tetragon<usize>, E3>::from_obj(
+            PositionEncoding::<E3>::default(),
+            obj.as_slice(),
+        )
+        .unwrap()
+        .0;
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        assert_eq!(6, buffer.as_index_slice().len());
+    }
+}
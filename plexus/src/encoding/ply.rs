@@ -7,6 +7,16 @@
 //! [PLY] support is implemented using the [`ply-rs`] crate and some of its
 //! types are re-exported here.
 //!
+//! [`PositionEncoding`] reads and writes vertex positions. [`AttributeDecoder`]
+//! reads arbitrary vertex and face data, such as normals, colors, and custom
+//! properties, and can aggregate properties from more than one element.
+//! [`FromEdgePly`] also reads edge data, such as crease weights, into a
+//! [`MeshGraph`].
+//!
+//! [`FromPlyStream`] reads large files incrementally. Rather than loading all
+//! elements into memory, vertices and faces are decoded in small batches and
+//! inserted directly into a mesh via the [builder API][`builder`].
+//!
 //! # Examples
 //!
 //! Reading a [PLY] file into a [`MeshGraph`]:
@@ -33,38 +43,101 @@
 //! let (graph, _) = MeshGraph::<E3>::from_ply(encoding, read()).unwrap();
 //! ```
 //!
+//! Writing a [`MeshGraph`] as binary [PLY]:
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::R64;
+//! use nalgebra::Point3;
+//! use plexus::encoding::ply::{
+//!     ElementDefinition, Format, Header, PositionEncoding, PropertyDefinition, PropertyType,
+//!     ScalarType, ToPly,
+//! };
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cube::Cube;
+//! use plexus::primitive::generate::Position;
+//!
+//! type E3 = Point3<R64>;
+//!
+//! let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+//!
+//! let mut definitions = Header::new();
+//! let mut vertex = ElementDefinition::new("vertex".to_owned());
+//! for name in &["x", "y", "z"] {
+//!     vertex.properties.insert(
+//!         (*name).to_owned(),
+//!         PropertyDefinition::new(
+//!             (*name).to_owned(),
+//!             PropertyType::Scalar(ScalarType::Float),
+//!         ),
+//!     );
+//! }
+//! let mut face = ElementDefinition::new("face".to_owned());
+//! face.properties.insert(
+//!     "vertex_index".to_owned(),
+//!     PropertyDefinition::new(
+//!         "vertex_index".to_owned(),
+//!         PropertyType::List(ScalarType::UChar, ScalarType::UInt),
+//!     ),
+//! );
+//! definitions.insert("vertex".to_owned(), vertex);
+//! definitions.insert("face".to_owned(), face);
+//!
+//! let mut ply = Vec::new();
+//! let encoding = PositionEncoding::<E3>::with_format(Format::BinaryLittleEndian);
+//! graph.to_ply(definitions, encoding, &mut ply).unwrap();
+//! ```
+//!
 //! [ply]: https://en.wikipedia.org/wiki/PLY_(file_format)
 //!
 //! [`ply-rs`]: https://crates.io/crates/ply-rs
 //!
+//! [`AttributeDecoder`]: crate::encoding::ply::AttributeDecoder
+//! [`builder`]: crate::builder
+//! [`FromEdgePly`]: crate::encoding::ply::FromEdgePly
 //! [`FromPly`]: crate::encoding::ply::FromPly
+//! [`FromPlyStream`]: crate::encoding::ply::FromPlyStream
+//! [`PositionEncoding`]: crate::encoding::ply::PositionEncoding
 //! [`ToPly`]: crate::encoding::ply::ToPly
 //! [`MeshGraph`]: crate::graph::MeshGraph
 
 #![cfg(feature = "encoding-ply")]
 
 use num::cast;
-use num::NumCast;
+use num::{Integer, NumCast};
 use ply_rs::parser::Parser;
-use ply_rs::ply::KeyMap;
+use ply_rs::ply::{Encoding, KeyMap};
+use ply_rs::writer::Writer;
 use smallvec::SmallVec;
-use std::io::{self, Read, Write};
-use std::iter::FromIterator;
+use std::cmp;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::iter::{self, FromIterator};
 use std::marker::PhantomData;
 use theon::space::{EuclideanSpace, FiniteDimensional};
+use theon::AsPosition;
+use thiserror::Error;
 use typenum::{NonZero, Unsigned, U2, U3};
 
-use crate::buffer::BufferError;
-use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
-use crate::graph::GraphError;
+use crate::buffer::{BufferError, MeshBuffer};
+use crate::builder::{Buildable, FacetBuilder, MeshBuilder, SurfaceBuilder};
+use crate::encoding::{
+    ArcDecoder, EdgeDecoder, FaceDecoder, FromEdgeEncoding, FromEncoding, VertexDecoder,
+};
+use crate::geometry::FromGeometry;
+use crate::graph::{ClosedView as _, GraphData, GraphError, MeshGraph};
+use crate::index::{Flat, Grouping};
+use crate::primitive::Polygonal;
 
 pub use ply_rs::ply::{
     ElementDef as ElementDefinition, Property, PropertyDef as PropertyDefinition, PropertyType,
+    ScalarType,
 };
 
-// TODO: These traits only allow a single element to be read for each topology
-//       (vertices, faces, etc.). It may be useful to allow code to aggregate
-//       various elements to produce an output for a single topology.
 // TODO: Consider using the new-type pattern to hide underlying types and expose
 //       a smaller and more tailored API surface.
 
@@ -72,11 +145,40 @@ pub type Header = KeyMap<ElementDefinition>;
 pub type Payload = KeyMap<Vec<Element>>;
 pub type Element = KeyMap<Property>;
 
+// The number of elements that are read at a time when streaming.
+const STREAM_CHUNK_SIZE: usize = 4096;
+
 pub struct Ply {
     pub header: Header,
     pub payload: Payload,
 }
 
+/// Format of encoded [PLY] data.
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Format {
+    Ascii,
+    BinaryBigEndian,
+    BinaryLittleEndian,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Format::Ascii
+    }
+}
+
+impl From<Format> for Encoding {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Ascii => Encoding::Ascii,
+            Format::BinaryBigEndian => Encoding::BinaryBigEndian,
+            Format::BinaryLittleEndian => Encoding::BinaryLittleEndian,
+        }
+    }
+}
+
 impl Ply {
     pub fn parse<R>(mut read: R) -> Result<Self, PlyError>
     where
@@ -90,30 +192,46 @@ impl Ply {
     }
 }
 
-#[derive(Debug)]
+/// Errors concerning the [PLY] encoding.
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+#[derive(Debug, Error)]
 pub enum PlyError {
-    ElementNotFound,
-    PropertyNotFound,
+    /// An element is not defined in the header or payload.
+    #[error("element `{name}` not found")]
+    ElementNotFound {
+        /// The name of the missing element.
+        name: String,
+    },
+    /// A property is not defined by an element.
+    #[error("property `{name}` not found")]
+    PropertyNotFound {
+        /// The name of the missing property.
+        name: String,
+    },
+    /// An item (a vertex, face, etc.) could not be decoded.
+    #[error("failed to decode element `{element}` at index {index}")]
+    Decode {
+        /// The name of the element.
+        ///
+        /// If the item is aggregated from more than one element, then this is
+        /// the name of the first aggregated element.
+        element: String,
+        /// The index of the item in its element.
+        index: usize,
+        /// The error encountered when decoding the item.
+        #[source]
+        source: Box<PlyError>,
+    },
+    /// Data could not be converted to or from its encoding.
+    #[error("failed to convert data")]
     Encoding,
-    Io(io::Error),
-}
-
-impl From<BufferError> for PlyError {
-    fn from(_: BufferError) -> Self {
-        PlyError::Encoding
-    }
-}
-
-impl From<io::Error> for PlyError {
-    fn from(error: io::Error) -> Self {
-        PlyError::Io(error)
-    }
-}
-
-impl From<GraphError> for PlyError {
-    fn from(_: GraphError) -> Self {
-        PlyError::Encoding
-    }
+    #[error("failed to build mesh buffer")]
+    Buffer(#[from] BufferError),
+    #[error("failed to build mesh graph")]
+    Graph(#[from] GraphError),
+    #[error("failed to read or write data")]
+    Io(#[from] io::Error),
 }
 
 pub trait ElementExt {
@@ -135,8 +253,11 @@ impl ElementExt for Element {
         K: AsRef<str>,
         T: NumCast,
     {
-        self.get(key.as_ref())
-            .ok_or_else(|| PlyError::PropertyNotFound)?
+        let key = key.as_ref();
+        self.get(key)
+            .ok_or_else(|| PlyError::PropertyNotFound {
+                name: key.to_owned(),
+            })?
             .clone()
             .into_scalar()
     }
@@ -147,8 +268,11 @@ impl ElementExt for Element {
         T: NumCast,
         I: FromIterator<T>,
     {
-        self.get(key.as_ref())
-            .ok_or_else(|| PlyError::PropertyNotFound)?
+        let key = key.as_ref();
+        self.get(key)
+            .ok_or_else(|| PlyError::PropertyNotFound {
+                name: key.to_owned(),
+            })?
             .clone()
             .into_list()
     }
@@ -202,6 +326,273 @@ impl PropertyExt for Property {
     }
 }
 
+/// Properties of an item aggregated from one or more elements.
+///
+/// Properties are looked up in each aggregated element in order, so the first
+/// element that defines a property provides its value.
+#[derive(Clone, Debug)]
+pub struct Attributes<'a> {
+    elements: SmallVec<[&'a Element; 2]>,
+}
+
+impl<'a> Attributes<'a> {
+    pub fn get<K>(&self, key: K) -> Option<&'a Property>
+    where
+        K: AsRef<str>,
+    {
+        self.elements
+            .iter()
+            .find_map(|element| element.get(key.as_ref()))
+    }
+
+    pub fn contains<K>(&self, key: K) -> bool
+    where
+        K: AsRef<str>,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Reads the `nx`, `ny`, and `nz` properties.
+    ///
+    /// Returns `None` if none of these properties are present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if only some of these properties are present or if
+    /// they cannot be converted.
+    pub fn normal<T>(&self) -> Result<Option<[T; 3]>, PlyError>
+    where
+        T: NumCast,
+    {
+        self.triple(["nx", "ny", "nz"])
+    }
+
+    /// Reads the `red`, `green`, `blue`, and `alpha` properties.
+    ///
+    /// If the `alpha` property is not present, then the color is opaque and
+    /// alpha is the maximum value of the type of the `red` property (e.g., 255
+    /// for `uchar` and 1.0 for `float`). Returns `None` if none of the color
+    /// channels are present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if only some of the color channels are present or if
+    /// they cannot be converted.
+    pub fn color<T>(&self) -> Result<Option<[T; 4]>, PlyError>
+    where
+        T: NumCast,
+    {
+        let [r, g, b] = match self.triple(["red", "green", "blue"])? {
+            Some(rgb) => rgb,
+            _ => {
+                return Ok(None);
+            }
+        };
+        let a = match self.get("alpha") {
+            Some(alpha) => alpha.clone().into_scalar()?,
+            _ => self
+                .get("red")
+                .ok_or_else(|| PlyError::PropertyNotFound {
+                    name: "red".to_owned(),
+                })
+                .and_then(opaque)?,
+        };
+        Ok(Some([r, g, b, a]))
+    }
+
+    /// Reads texture coordinates from the `s` and `t`, `u` and `v`,
+    /// `texture_u` and `texture_v`, or `texture_s` and `texture_t` properties.
+    ///
+    /// Returns `None` if none of these properties are present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if only one coordinate of a pair is present or if the
+    /// coordinates cannot be converted.
+    pub fn texture<T>(&self) -> Result<Option<[T; 2]>, PlyError>
+    where
+        T: NumCast,
+    {
+        for (u, v) in &[
+            ("s", "t"),
+            ("u", "v"),
+            ("texture_u", "texture_v"),
+            ("texture_s", "texture_t"),
+        ] {
+            if self.contains(u) {
+                return Ok(Some([self.scalar(u)?, self.scalar(v)?]));
+            }
+        }
+        Ok(None)
+    }
+
+    fn triple<T>(&self, keys: [&str; 3]) -> Result<Option<[T; 3]>, PlyError>
+    where
+        T: NumCast,
+    {
+        if keys.iter().any(|key| self.contains(key)) {
+            let [x, y, z] = keys;
+            Ok(Some([self.scalar(x)?, self.scalar(y)?, self.scalar(z)?]))
+        }
+        else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a> ElementExt for Attributes<'a> {
+    fn scalar<K, T>(&self, key: K) -> Result<T, PlyError>
+    where
+        K: AsRef<str>,
+        T: NumCast,
+    {
+        let key = key.as_ref();
+        self.get(key)
+            .ok_or_else(|| PlyError::PropertyNotFound {
+                name: key.to_owned(),
+            })?
+            .clone()
+            .into_scalar()
+    }
+
+    fn list<K, T, I>(&self, key: K) -> Result<I, PlyError>
+    where
+        K: AsRef<str>,
+        T: NumCast,
+        I: FromIterator<T>,
+    {
+        let key = key.as_ref();
+        self.get(key)
+            .ok_or_else(|| PlyError::PropertyNotFound {
+                name: key.to_owned(),
+            })?
+            .clone()
+            .into_list()
+    }
+}
+
+impl<'a> From<&'a Element> for Attributes<'a> {
+    fn from(element: &'a Element) -> Self {
+        Attributes {
+            elements: iter::once(element).collect(),
+        }
+    }
+}
+
+/// Elements aggregated for a topology (vertices, faces, etc.).
+///
+/// Elements are aggregated by index: the elements at the same index in each
+/// aggregated element list describe the same item. For example, vertex
+/// positions and colors may be read from separate `vertex` and `color`
+/// elements.
+#[derive(Clone, Debug)]
+pub struct Elements<'a> {
+    elements: SmallVec<[(&'a ElementDefinition, &'a [Element]); 2]>,
+    // The index of the first item. This is non-zero when elements are
+    // streamed in batches.
+    offset: usize,
+}
+
+impl<'a> Elements<'a> {
+    /// Aggregates element definitions and their elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no elements are given or if the number of elements
+    /// differs between element definitions.
+    pub fn aggregate<I>(elements: I) -> Result<Self, PlyError>
+    where
+        I: IntoIterator<Item = (&'a ElementDefinition, &'a Vec<Element>)>,
+    {
+        let elements = elements
+            .into_iter()
+            .map(|(definition, elements)| (definition, elements.as_slice()))
+            .collect::<SmallVec<[_; 2]>>();
+        match elements.first() {
+            Some((_, first)) => {
+                if elements.iter().any(|(_, other)| other.len() != first.len()) {
+                    return Err(PlyError::Encoding);
+                }
+            }
+            _ => {
+                return Err(PlyError::Encoding);
+            }
+        }
+        Ok(Elements {
+            elements,
+            offset: 0,
+        })
+    }
+
+    pub fn definitions(&self) -> impl ExactSizeIterator<Item = &'a ElementDefinition> + '_ {
+        self.elements.iter().map(|(definition, _)| *definition)
+    }
+
+    /// Gets the aggregated properties of each item.
+    pub fn attributes(&self) -> impl ExactSizeIterator<Item = Attributes<'a>> + '_ {
+        (0..self.len()).map(move |index| Attributes {
+            elements: self
+                .elements
+                .iter()
+                .map(|(_, elements)| &elements[index])
+                .collect(),
+        })
+    }
+
+    /// Decodes each item with the given function.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlyError::Decode`] if the function fails for any item. The
+    /// error includes the index of the item and the error returned by the
+    /// function.
+    ///
+    /// [`PlyError::Decode`]: crate::encoding::ply::PlyError::Decode
+    pub fn decode_with<T, F>(&self, mut f: F) -> Result<Vec<T>, PlyError>
+    where
+        F: FnMut(&Attributes<'a>) -> Result<T, PlyError>,
+    {
+        self.attributes()
+            .enumerate()
+            .map(|(index, attributes)| f(&attributes).map_err(|error| self.context(index, error)))
+            .collect()
+    }
+
+    /// Gets the number of items.
+    pub fn len(&self) -> usize {
+        self.elements[0].1.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Gets the definition and elements if only one element is aggregated.
+    fn single(&self) -> Result<(&'a ElementDefinition, &'a [Element]), PlyError> {
+        match self.elements.as_slice() {
+            [(definition, elements)] => Ok((*definition, *elements)),
+            _ => Err(PlyError::Encoding),
+        }
+    }
+
+    fn context(&self, index: usize, error: PlyError) -> PlyError {
+        PlyError::Decode {
+            element: self.elements[0].0.name.clone(),
+            index: self.offset + index,
+            source: Box::new(error),
+        }
+    }
+}
+
+impl<'a> From<(&'a ElementDefinition, &'a Vec<Element>)> for Elements<'a> {
+    fn from((definition, elements): (&'a ElementDefinition, &'a Vec<Element>)) -> Self {
+        Elements {
+            elements: iter::once((definition, elements.as_slice())).collect(),
+            offset: 0,
+        }
+    }
+}
+
 pub trait VertexElementDecoder {
     fn decode_vertex_elements<'a>(
         &self,
@@ -210,6 +601,19 @@ pub trait VertexElementDecoder {
     ) -> Result<(&'a ElementDefinition, &'a Vec<Element>), PlyError> {
         decode_elements(definitions, elements, "vertex")
     }
+
+    /// Aggregates the elements from which vertices are decoded.
+    ///
+    /// By default, only the element given by `decode_vertex_elements` is
+    /// aggregated.
+    fn aggregate_vertex_elements<'a>(
+        &self,
+        definitions: &'a Header,
+        elements: &'a Payload,
+    ) -> Result<Elements<'a>, PlyError> {
+        self.decode_vertex_elements(definitions, elements)
+            .map(Elements::from)
+    }
 }
 
 pub trait VertexPropertyDecoder: VertexDecoder {
@@ -220,6 +624,21 @@ pub trait VertexPropertyDecoder: VertexDecoder {
     ) -> Result<Self::Output, PlyError>
     where
         I: IntoIterator<Item = &'a Element>;
+
+    /// Decodes vertices from aggregated elements.
+    ///
+    /// By default, `decode_vertex_properties` is used to decode the elements.
+    ///
+    /// # Errors
+    ///
+    /// By default, returns an error if more than one element is aggregated.
+    fn decode_aggregated_vertex_properties(
+        &self,
+        elements: &Elements,
+    ) -> Result<Self::Output, PlyError> {
+        let (definition, elements) = elements.single()?;
+        self.decode_vertex_properties(definition, elements)
+    }
 }
 
 pub trait FaceElementDecoder {
@@ -230,6 +649,19 @@ pub trait FaceElementDecoder {
     ) -> Result<(&'a ElementDefinition, &'a Vec<Element>), PlyError> {
         decode_elements(definitions, elements, "face")
     }
+
+    /// Aggregates the elements from which faces are decoded.
+    ///
+    /// By default, only the element given by `decode_face_elements` is
+    /// aggregated.
+    fn aggregate_face_elements<'a>(
+        &self,
+        definitions: &'a Header,
+        elements: &'a Payload,
+    ) -> Result<Elements<'a>, PlyError> {
+        self.decode_face_elements(definitions, elements)
+            .map(Elements::from)
+    }
 }
 
 pub trait FacePropertyDecoder: FaceDecoder {
@@ -240,6 +672,56 @@ pub trait FacePropertyDecoder: FaceDecoder {
     ) -> Result<Self::Output, PlyError>
     where
         I: IntoIterator<Item = &'a Element>;
+
+    /// Decodes faces from aggregated elements.
+    ///
+    /// By default, `decode_face_properties` is used to decode the elements.
+    ///
+    /// # Errors
+    ///
+    /// By default, returns an error if more than one element is aggregated.
+    fn decode_aggregated_face_properties(
+        &self,
+        elements: &Elements,
+    ) -> Result<Self::Output, PlyError> {
+        let (definition, elements) = elements.single()?;
+        self.decode_face_properties(definition, elements)
+    }
+}
+
+pub trait EdgeElementDecoder {
+    fn decode_edge_elements<'a>(
+        &self,
+        definitions: &'a Header,
+        elements: &'a Payload,
+    ) -> Result<Elements<'a>, PlyError> {
+        decode_elements(definitions, elements, "edge").map(Elements::from)
+    }
+}
+
+/// Decodes edge data from elements.
+///
+/// Each edge is identified by the `vertex1` and `vertex2` properties of its
+/// element.
+pub trait EdgePropertyDecoder: EdgeDecoder {
+    fn decode_edge_properties(&self, elements: &Elements) -> Result<Self::Output, PlyError>;
+}
+
+/// Vertex or face data that can be decoded from [PLY] properties.
+///
+/// See [`AttributeDecoder`].
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+///
+/// [`AttributeDecoder`]: crate::encoding::ply::AttributeDecoder
+pub trait DecodeAttributes: Sized {
+    fn decode_attributes(attributes: &Attributes) -> Result<Self, PlyError>;
+}
+
+impl DecodeAttributes for () {
+    fn decode_attributes(_: &Attributes) -> Result<Self, PlyError> {
+        Ok(())
+    }
 }
 
 pub trait FromPly<E>: Sized {
@@ -267,24 +749,315 @@ where
     }
 }
 
+/// Conversion from [PLY] with edge data.
+///
+/// Unlike [`FromPly`], this trait also decodes edge elements, such as those
+/// that describe crease weights or seams. Edges are identified by the
+/// `vertex1` and `vertex2` properties of these elements and must be formed by
+/// faces. [PLY] does not describe arcs, so arcs are given default data.
+///
+/// # Errors
+///
+/// Returns an error if the edge elements are not found or if an edge is not
+/// formed by faces.
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+///
+/// [`FromPly`]: crate::encoding::ply::FromPly
+pub trait FromEdgePly<E>: Sized {
+    fn from_edge_ply<R>(decoder: E, read: R) -> Result<(Self, Ply), PlyError>
+    where
+        R: Read;
+}
+
+impl<T, E> FromEdgePly<E> for T
+where
+    T: FromEdgeEncoding<E>,
+    PlyError: From<<T as FromEncoding<E>>::Error>,
+    E: ArcDecoder
+        + EdgeElementDecoder
+        + EdgePropertyDecoder
+        + FaceElementDecoder
+        + FacePropertyDecoder
+        + VertexPropertyDecoder
+        + VertexElementDecoder,
+    <E as ArcDecoder>::Output: Default,
+{
+    fn from_edge_ply<R>(decoder: E, mut read: R) -> Result<(Self, Ply), PlyError>
+    where
+        R: Read,
+    {
+        let ply = Ply::parse(&mut read)?;
+        let mesh = T::from_edge_encoding(
+            decode_vertex_properties(&decoder, &ply.header, &ply.payload)?,
+            decode_face_properties(&decoder, &ply.header, &ply.payload)?,
+            Default::default(),
+            decode_edge_properties(&decoder, &ply.header, &ply.payload)?,
+        )?;
+        Ok((mesh, ply))
+    }
+}
+
+/// Incremental conversion from [PLY].
+///
+/// Unlike [`FromPly`], this trait does not load the payload of a [PLY] file
+/// into memory. Elements are read and decoded in small batches and vertices and
+/// faces are inserted directly into a mesh using its [builder][`Buildable`].
+/// Elements that are not decoded are read and discarded and reading stops
+/// after faces have been decoded. Returns the mesh and the header of the [PLY]
+/// file.
+///
+/// # Errors
+///
+/// Returns an error if the decoder aggregates properties from more than one
+/// element or if the face element precedes the vertex element, because such
+/// data cannot be decoded without buffering elements.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::encoding::ply::{FromPlyStream, PositionEncoding};
+/// use plexus::graph::MeshGraph;
+///
+/// type E3 = Point3<f64>;
+///
+/// let ply: &[u8] = include_bytes!("../../../data/cube.ply");
+/// let (graph, _) = MeshGraph::<E3>::from_ply_stream(PositionEncoding::<E3>::default(), ply)
+///     .unwrap();
+/// ```
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+///
+/// [`Buildable`]: crate::builder::Buildable
+/// [`FromPly`]: crate::encoding::ply::FromPly
+pub trait FromPlyStream<E>: Sized {
+    fn from_ply_stream<R>(decoder: E, read: R) -> Result<(Self, Header), PlyError>
+    where
+        R: Read;
+}
+
+impl<T, E> FromPlyStream<E> for T
+where
+    T: Buildable,
+    T::Facet: FromGeometry<<E as FaceDecoder>::Face>,
+    T::Vertex: FromGeometry<<E as VertexDecoder>::Vertex>,
+    PlyError: From<T::Error>,
+    E: FaceElementDecoder + FacePropertyDecoder + VertexPropertyDecoder + VertexElementDecoder,
+{
+    fn from_ply_stream<R>(decoder: E, read: R) -> Result<(Self, Header), PlyError>
+    where
+        R: Read,
+    {
+        let mut read = BufReader::new(read);
+        let parser = Parser::<Element>::new();
+        let header = parser.read_header(&mut read)?;
+        let mut builder = T::builder();
+        builder.surface_with(|builder| {
+            Ok::<_, T::Error>(stream_surface(
+                &decoder, &parser, &header, &mut read, builder,
+            ))
+        })??;
+        let mesh = builder.build()?;
+        Ok((mesh, header.elements))
+    }
+}
+
+pub trait FormatEncoder {
+    fn format(&self) -> Format;
+}
+
+pub trait VertexElementEncoder {
+    fn encode_vertex_elements<'a>(
+        &self,
+        definitions: &'a Header,
+    ) -> Result<&'a ElementDefinition, PlyError> {
+        encode_elements(definitions, "vertex")
+    }
+}
+
+pub trait VertexPropertyEncoder<T> {
+    fn encode_vertex_properties(
+        &self,
+        definition: &ElementDefinition,
+        vertex: &T,
+    ) -> Result<Element, PlyError>;
+}
+
+pub trait FaceElementEncoder {
+    fn encode_face_elements<'a>(
+        &self,
+        definitions: &'a Header,
+    ) -> Result<&'a ElementDefinition, PlyError> {
+        encode_elements(definitions, "face")
+    }
+}
+
+pub trait FacePropertyEncoder<T> {
+    fn encode_face_properties(
+        &self,
+        definition: &ElementDefinition,
+        indices: &[usize],
+        face: &T,
+    ) -> Result<Element, PlyError>;
+}
+
+/// Conversion into [PLY].
+///
+/// Only the vertex and face elements of the given header definitions are
+/// written. The encoder is given the definition of each element and must
+/// provide a value for each property in the definition using the type of that
+/// property.
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
 pub trait ToPly<E> {
+    /// Writes [PLY] data using the given header definitions and encoder.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex or face element is not defined, a
+    /// property in the definitions cannot be encoded, or the data cannot be
+    /// written.
+    ///
+    /// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
     fn to_ply<W>(&self, definitions: Header, encoder: E, write: W) -> Result<usize, PlyError>
     where
         W: Write;
 }
 
+impl<E, A, N, G> ToPly<E> for MeshBuffer<Flat<A, N>, G>
+where
+    E: FaceElementEncoder
+        + FacePropertyEncoder<()>
+        + FormatEncoder
+        + VertexElementEncoder
+        + VertexPropertyEncoder<G>,
+    A: NonZero + Unsigned,
+    N: Copy + Integer + NumCast + num::Unsigned,
+{
+    fn to_ply<W>(&self, definitions: Header, encoder: E, write: W) -> Result<usize, PlyError>
+    where
+        W: Write,
+    {
+        let vertices = {
+            let definition = encoder.encode_vertex_elements(&definitions)?;
+            self.as_vertex_slice()
+                .iter()
+                .map(|vertex| encoder.encode_vertex_properties(definition, vertex))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let faces = {
+            let definition = encoder.encode_face_elements(&definitions)?;
+            self.as_index_slice()
+                .chunks(A::USIZE)
+                .map(|chunk| {
+                    let indices = num_cast_list::<_, usize, SmallVec<[_; 4]>>(chunk.to_vec())?;
+                    encoder.encode_face_properties(definition, indices.as_slice(), &())
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        encode_ply(&encoder, definitions, vertices, faces, write)
+    }
+}
+
+impl<E, P, G> ToPly<E> for MeshBuffer<P, G>
+where
+    E: FaceElementEncoder
+        + FacePropertyEncoder<()>
+        + FormatEncoder
+        + VertexElementEncoder
+        + VertexPropertyEncoder<G>,
+    P: Grouping<Group = P> + Polygonal,
+    P::Vertex: Copy + Integer + NumCast + num::Unsigned,
+{
+    fn to_ply<W>(&self, definitions: Header, encoder: E, write: W) -> Result<usize, PlyError>
+    where
+        W: Write,
+    {
+        let vertices = {
+            let definition = encoder.encode_vertex_elements(&definitions)?;
+            self.as_vertex_slice()
+                .iter()
+                .map(|vertex| encoder.encode_vertex_properties(definition, vertex))
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let faces = {
+            let definition = encoder.encode_face_elements(&definitions)?;
+            self.as_index_slice()
+                .iter()
+                .map(|polygon| {
+                    let indices =
+                        num_cast_list::<_, usize, SmallVec<[_; 4]>>(polygon.as_ref().to_vec())?;
+                    encoder.encode_face_properties(definition, indices.as_slice(), &())
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        encode_ply(&encoder, definitions, vertices, faces, write)
+    }
+}
+
+impl<E, G> ToPly<E> for MeshGraph<G>
+where
+    E: FaceElementEncoder
+        + FacePropertyEncoder<G::Face>
+        + FormatEncoder
+        + VertexElementEncoder
+        + VertexPropertyEncoder<G::Vertex>,
+    G: GraphData,
+{
+    fn to_ply<W>(&self, definitions: Header, encoder: E, write: W) -> Result<usize, PlyError>
+    where
+        W: Write,
+    {
+        let mut indices = HashMap::with_capacity(self.vertex_count());
+        let vertices = {
+            let definition = encoder.encode_vertex_elements(&definitions)?;
+            self.vertices()
+                .enumerate()
+                .map(|(index, vertex)| {
+                    indices.insert(vertex.key(), index);
+                    encoder.encode_vertex_properties(definition, &vertex.data)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let faces = {
+            let definition = encoder.encode_face_elements(&definitions)?;
+            self.faces()
+                .map(|face| {
+                    let perimeter = face
+                        .adjacent_vertices()
+                        .map(|vertex| indices[&vertex.key()])
+                        .collect::<SmallVec<[_; 8]>>();
+                    encoder.encode_face_properties(definition, perimeter.as_slice(), &face.data)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        encode_ply(&encoder, definitions, vertices, faces, write)
+    }
+}
+
 pub trait DecodePosition<N>: FiniteDimensional<N = N> + Sized
 where
     N: NonZero + Unsigned,
 {
-    fn decode_position(element: &Element) -> Result<Self, PlyError>;
+    fn decode_position<E>(element: &E) -> Result<Self, PlyError>
+    where
+        E: ElementExt;
 }
 
 impl<T> DecodePosition<U2> for T
 where
     T: EuclideanSpace + FiniteDimensional<N = U2>,
 {
-    fn decode_position(element: &Element) -> Result<Self, PlyError> {
+    fn decode_position<E>(element: &E) -> Result<Self, PlyError>
+    where
+        E: ElementExt,
+    {
         let position = EuclideanSpace::from_xy(element.scalar("x")?, element.scalar("y")?);
         Ok(position)
     }
@@ -294,7 +1067,10 @@ impl<T> DecodePosition<U3> for T
 where
     T: EuclideanSpace + FiniteDimensional<N = U3>,
 {
-    fn decode_position(element: &Element) -> Result<Self, PlyError> {
+    fn decode_position<E>(element: &E) -> Result<Self, PlyError>
+    where
+        E: ElementExt,
+    {
         let position = EuclideanSpace::from_xyz(
             element.scalar("x")?,
             element.scalar("y")?,
@@ -304,18 +1080,76 @@ where
     }
 }
 
-pub struct PositionEncoding<T> {
-    phantom: PhantomData<T>,
+pub trait EncodePosition<N>: FiniteDimensional<N = N> + Sized
+where
+    N: NonZero + Unsigned,
+{
+    fn encode_position(&self, definition: &PropertyDefinition) -> Result<Property, PlyError>;
 }
 
-impl<T> Default for PositionEncoding<T> {
-    fn default() -> Self {
-        PositionEncoding {
-            phantom: PhantomData,
+impl<T> EncodePosition<U2> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    fn encode_position(&self, definition: &PropertyDefinition) -> Result<Property, PlyError> {
+        let (x, y) = self.into_xy();
+        match definition.name.as_str() {
+            "x" => num_cast_property(x, &definition.data_type),
+            "y" => num_cast_property(y, &definition.data_type),
+            _ => Err(PlyError::PropertyNotFound {
+                name: definition.name.clone(),
+            }),
+        }
+    }
+}
+
+impl<T> EncodePosition<U3> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn encode_position(&self, definition: &PropertyDefinition) -> Result<Property, PlyError> {
+        let (x, y, z) = self.into_xyz();
+        match definition.name.as_str() {
+            "x" => num_cast_property(x, &definition.data_type),
+            "y" => num_cast_property(y, &definition.data_type),
+            "z" => num_cast_property(z, &definition.data_type),
+            _ => Err(PlyError::PropertyNotFound {
+                name: definition.name.clone(),
+            }),
+        }
+    }
+}
+
+/// Encoding of vertex positions and face indices.
+///
+/// When decoding, the `x`, `y`, and `z` properties of the `vertex` element are
+/// read as positions and the `vertex_index` property of the `face` element is
+/// read as indices into those positions. If the `vertex_index` property is not
+/// present, then the `vertex_indices` property is read instead. When encoding,
+/// the `x`, `y`, `z`, and `vertex_index` properties are written using the types given by the header definitions and the
+/// [`Format`] of the encoding.
+///
+/// [`Format`]: crate::encoding::ply::Format
+pub struct PositionEncoding<T> {
+    format: Format,
+    phantom: PhantomData<T>,
+}
+
+impl<T> PositionEncoding<T> {
+    pub fn with_format(format: Format) -> Self {
+        PositionEncoding {
+            format,
+            phantom: PhantomData,
         }
     }
 }
 
+impl<T> Default for PositionEncoding<T> {
+    fn default() -> Self {
+        PositionEncoding::with_format(Default::default())
+    }
+}
+
 impl<T> FaceDecoder for PositionEncoding<T> {
     type Output = Vec<(Self::Index, Self::Face)>;
     type Index = SmallVec<[usize; 4]>;
@@ -324,6 +1158,8 @@ impl<T> FaceDecoder for PositionEncoding<T> {
 
 impl<T> FaceElementDecoder for PositionEncoding<T> {}
 
+impl<T> FaceElementEncoder for PositionEncoding<T> {}
+
 impl<T> FacePropertyDecoder for PositionEncoding<T> {
     fn decode_face_properties<'a, I>(
         &self,
@@ -335,12 +1171,49 @@ impl<T> FacePropertyDecoder for PositionEncoding<T> {
     {
         elements
             .into_iter()
-            .map(|element| {
-                let indices = element.list("vertex_index")?;
-                Ok((indices, ()))
-            })
+            .map(|element| decode_indices(&Attributes::from(element)))
             .collect()
     }
+
+    fn decode_aggregated_face_properties(
+        &self,
+        elements: &Elements,
+    ) -> Result<<Self as FaceDecoder>::Output, PlyError> {
+        elements.decode_with(decode_indices)
+    }
+}
+
+impl<T, F> FacePropertyEncoder<F> for PositionEncoding<T> {
+    fn encode_face_properties(
+        &self,
+        definition: &ElementDefinition,
+        indices: &[usize],
+        _: &F,
+    ) -> Result<Element, PlyError> {
+        let mut element = Element::new();
+        for property in definition.properties.values() {
+            match property.name.as_str() {
+                "vertex_index" => {
+                    element.insert(
+                        property.name.clone(),
+                        num_cast_list_property(indices.iter().cloned(), &property.data_type)?,
+                    );
+                }
+                _ => {
+                    return Err(PlyError::PropertyNotFound {
+                        name: property.name.clone(),
+                    });
+                }
+            }
+        }
+        Ok(element)
+    }
+}
+
+impl<T> FormatEncoder for PositionEncoding<T> {
+    fn format(&self) -> Format {
+        self.format
+    }
 }
 
 impl<T> VertexDecoder for PositionEncoding<T> {
@@ -350,6 +1223,8 @@ impl<T> VertexDecoder for PositionEncoding<T> {
 
 impl<T> VertexElementDecoder for PositionEncoding<T> {}
 
+impl<T> VertexElementEncoder for PositionEncoding<T> {}
+
 impl<T, N> VertexPropertyDecoder for PositionEncoding<T>
 where
     T: DecodePosition<N> + FiniteDimensional<N = N>,
@@ -368,6 +1243,328 @@ where
             .map(|element| T::decode_position(element))
             .collect()
     }
+
+    fn decode_aggregated_vertex_properties(
+        &self,
+        elements: &Elements,
+    ) -> Result<<Self as VertexDecoder>::Output, PlyError> {
+        elements.decode_with(|attributes| T::decode_position(attributes))
+    }
+}
+
+impl<T, N, V> VertexPropertyEncoder<V> for PositionEncoding<T>
+where
+    T: EncodePosition<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+    V: AsPosition<Position = T>,
+{
+    fn encode_vertex_properties(
+        &self,
+        definition: &ElementDefinition,
+        vertex: &V,
+    ) -> Result<Element, PlyError> {
+        let position = vertex.as_position();
+        let mut element = Element::new();
+        for property in definition.properties.values() {
+            element.insert(property.name.clone(), position.encode_position(property)?);
+        }
+        Ok(element)
+    }
+}
+
+/// Decoding of arbitrary vertex and face data.
+///
+/// When decoding, vertex and face data are read via [`DecodeAttributes`] from
+/// the properties of one or more aggregated elements and face indices are read
+/// from the first index property found in the face elements. By default,
+/// vertices are read from the `vertex` element, faces are read from the `face`
+/// element, and indices are read from either the `vertex_index` or
+/// `vertex_indices` property.
+///
+/// Edge data is read by [`FromEdgePly`] from the `edge` element by default.
+/// Edges are identified by the `vertex1` and `vertex2` properties.
+///
+/// # Examples
+///
+/// Reading colored vertices:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::encoding::ply::{
+///     AttributeDecoder, Attributes, DecodeAttributes, ElementExt, FromPly, PlyError,
+/// };
+/// use plexus::graph::{GraphData, MeshGraph};
+///
+/// #[derive(Clone, Copy)]
+/// pub struct Vertex {
+///     pub position: Point3<f64>,
+///     pub color: [u8; 4],
+/// }
+///
+/// impl GraphData for Vertex {
+///     type Vertex = Self;
+///     type Arc = ();
+///     type Edge = ();
+///     type Face = ();
+/// }
+///
+/// impl DecodeAttributes for Vertex {
+///     fn decode_attributes(attributes: &Attributes) -> Result<Self, PlyError> {
+///         Ok(Vertex {
+///             position: Point3::new(
+///                 attributes.scalar("x")?,
+///                 attributes.scalar("y")?,
+///                 attributes.scalar("z")?,
+///             ),
+///             color: attributes.color()?.unwrap_or([255; 4]),
+///         })
+///     }
+/// }
+///
+/// # let ply: &[u8] = b"\
+/// #     ply\n\
+/// #     format ascii 1.0\n\
+/// #     element vertex 3\n\
+/// #     property float x\n\
+/// #     property float y\n\
+/// #     property float z\n\
+/// #     element color 3\n\
+/// #     property uchar red\n\
+/// #     property uchar green\n\
+/// #     property uchar blue\n\
+/// #     element face 1\n\
+/// #     property list uchar uint vertex_indices\n\
+/// #     end_header\n\
+/// #     0 0 0\n\
+/// #     1 0 0\n\
+/// #     0 1 0\n\
+/// #     255 0 0\n\
+/// #     0 255 0\n\
+/// #     0 0 255\n\
+/// #     3 0 1 2\n\
+/// # ";
+/// let decoder = AttributeDecoder::<Vertex>::default().with_vertex_elements(&["vertex", "color"]);
+/// let (graph, _) = MeshGraph::<Vertex>::from_ply(decoder, ply).unwrap();
+/// ```
+///
+/// [`DecodeAttributes`]: crate::encoding::ply::DecodeAttributes
+/// [`FromEdgePly`]: crate::encoding::ply::FromEdgePly
+pub struct AttributeDecoder<V, F = (), E = ()> {
+    vertex: Vec<String>,
+    face: Vec<String>,
+    edge: Vec<String>,
+    indices: Vec<String>,
+    phantom: PhantomData<(V, F, E)>,
+}
+
+impl<V, F, E> AttributeDecoder<V, F, E> {
+    /// Reads vertices from the given elements.
+    pub fn with_vertex_elements<I>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.vertex = keys
+            .into_iter()
+            .map(|key| key.as_ref().to_owned())
+            .collect();
+        self
+    }
+
+    /// Reads faces from the given elements.
+    pub fn with_face_elements<I>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.face = keys
+            .into_iter()
+            .map(|key| key.as_ref().to_owned())
+            .collect();
+        self
+    }
+
+    /// Reads edges from the given elements.
+    ///
+    /// Edges are only read by [`FromEdgePly`].
+    ///
+    /// [`FromEdgePly`]: crate::encoding::ply::FromEdgePly
+    pub fn with_edge_elements<I>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.edge = keys
+            .into_iter()
+            .map(|key| key.as_ref().to_owned())
+            .collect();
+        self
+    }
+
+    /// Reads face indices from the first of the given properties found in the
+    /// face elements.
+    pub fn with_index_properties<I>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.indices = keys
+            .into_iter()
+            .map(|key| key.as_ref().to_owned())
+            .collect();
+        self
+    }
+}
+
+impl<V, F, E> AttributeDecoder<V, F, E>
+where
+    F: DecodeAttributes,
+{
+    fn decode_face(&self, attributes: &Attributes) -> Result<(SmallVec<[usize; 4]>, F), PlyError> {
+        let key = self
+            .indices
+            .iter()
+            .find(|key| attributes.contains(key))
+            .ok_or_else(|| PlyError::PropertyNotFound {
+                name: self.indices.first().cloned().unwrap_or_default(),
+            })?;
+        let indices = attributes.list(key)?;
+        Ok((indices, F::decode_attributes(attributes)?))
+    }
+}
+
+impl<V, F, E> Default for AttributeDecoder<V, F, E> {
+    fn default() -> Self {
+        AttributeDecoder {
+            vertex: vec!["vertex".to_owned()],
+            face: vec!["face".to_owned()],
+            edge: vec!["edge".to_owned()],
+            indices: vec!["vertex_index".to_owned(), "vertex_indices".to_owned()],
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<V, F, E> FaceDecoder for AttributeDecoder<V, F, E> {
+    type Output = Vec<(Self::Index, Self::Face)>;
+    type Index = SmallVec<[usize; 4]>;
+    type Face = F;
+}
+
+impl<V, F, E> FaceElementDecoder for AttributeDecoder<V, F, E> {
+    fn aggregate_face_elements<'a>(
+        &self,
+        definitions: &'a Header,
+        elements: &'a Payload,
+    ) -> Result<Elements<'a>, PlyError> {
+        aggregate_elements(definitions, elements, &self.face)
+    }
+}
+
+impl<V, F, E> FacePropertyDecoder for AttributeDecoder<V, F, E>
+where
+    F: DecodeAttributes,
+{
+    fn decode_face_properties<'a, I>(
+        &self,
+        _: &'a ElementDefinition,
+        elements: I,
+    ) -> Result<<Self as FaceDecoder>::Output, PlyError>
+    where
+        I: IntoIterator<Item = &'a Element>,
+    {
+        elements
+            .into_iter()
+            .map(|element| self.decode_face(&Attributes::from(element)))
+            .collect()
+    }
+
+    fn decode_aggregated_face_properties(
+        &self,
+        elements: &Elements,
+    ) -> Result<<Self as FaceDecoder>::Output, PlyError> {
+        elements.decode_with(|attributes| self.decode_face(attributes))
+    }
+}
+
+// PLY does not describe arcs, so no arc data is decoded.
+impl<V, F, E> ArcDecoder for AttributeDecoder<V, F, E> {
+    type Output = Vec<((usize, usize), Self::Arc)>;
+    type Arc = ();
+}
+
+impl<V, F, E> EdgeDecoder for AttributeDecoder<V, F, E> {
+    type Output = Vec<((usize, usize), Self::Edge)>;
+    type Edge = E;
+}
+
+impl<V, F, E> EdgeElementDecoder for AttributeDecoder<V, F, E> {
+    fn decode_edge_elements<'a>(
+        &self,
+        definitions: &'a Header,
+        elements: &'a Payload,
+    ) -> Result<Elements<'a>, PlyError> {
+        aggregate_elements(definitions, elements, &self.edge)
+    }
+}
+
+impl<V, F, E> EdgePropertyDecoder for AttributeDecoder<V, F, E>
+where
+    E: DecodeAttributes,
+{
+    fn decode_edge_properties(
+        &self,
+        elements: &Elements,
+    ) -> Result<<Self as EdgeDecoder>::Output, PlyError> {
+        elements.decode_with(|attributes| {
+            let indices = (attributes.scalar("vertex1")?, attributes.scalar("vertex2")?);
+            Ok((indices, E::decode_attributes(attributes)?))
+        })
+    }
+}
+
+impl<V, F, E> VertexDecoder for AttributeDecoder<V, F, E> {
+    type Output = Vec<Self::Vertex>;
+    type Vertex = V;
+}
+
+impl<V, F, E> VertexElementDecoder for AttributeDecoder<V, F, E> {
+    fn aggregate_vertex_elements<'a>(
+        &self,
+        definitions: &'a Header,
+        elements: &'a Payload,
+    ) -> Result<Elements<'a>, PlyError> {
+        aggregate_elements(definitions, elements, &self.vertex)
+    }
+}
+
+impl<V, F, E> VertexPropertyDecoder for AttributeDecoder<V, F, E>
+where
+    V: DecodeAttributes,
+{
+    fn decode_vertex_properties<'a, I>(
+        &self,
+        _: &'a ElementDefinition,
+        elements: I,
+    ) -> Result<<Self as VertexDecoder>::Output, PlyError>
+    where
+        I: IntoIterator<Item = &'a Element>,
+    {
+        elements
+            .into_iter()
+            .map(|element| V::decode_attributes(&Attributes::from(element)))
+            .collect()
+    }
+
+    fn decode_aggregated_vertex_properties(
+        &self,
+        elements: &Elements,
+    ) -> Result<<Self as VertexDecoder>::Output, PlyError> {
+        elements.decode_with(|attributes| V::decode_attributes(attributes))
+    }
 }
 
 pub fn decode_elements<'a, K>(
@@ -378,15 +1575,33 @@ pub fn decode_elements<'a, K>(
 where
     K: AsRef<str>,
 {
+    let key = key.as_ref();
     definitions
-        .get(key.as_ref())
-        .ok_or_else(|| PlyError::ElementNotFound)
+        .get(key)
         .and_then(|definition| {
             elements
                 .get(&definition.name)
-                .ok_or_else(|| PlyError::ElementNotFound)
                 .map(|elements| (definition, elements))
         })
+        .ok_or_else(|| PlyError::ElementNotFound {
+            name: key.to_owned(),
+        })
+}
+
+pub fn aggregate_elements<'a, I>(
+    definitions: &'a Header,
+    elements: &'a Payload,
+    keys: I,
+) -> Result<Elements<'a>, PlyError>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let elements = keys
+        .into_iter()
+        .map(|key| decode_elements(definitions, elements, key))
+        .collect::<Result<SmallVec<[_; 2]>, _>>()?;
+    Elements::aggregate(elements)
 }
 
 pub fn decode_vertex_properties<E>(
@@ -398,8 +1613,8 @@ where
     E: VertexElementDecoder + VertexPropertyDecoder,
 {
     decoder
-        .decode_vertex_elements(definitions, elements)
-        .and_then(|(definition, elements)| decoder.decode_vertex_properties(definition, elements))
+        .aggregate_vertex_elements(definitions, elements)
+        .and_then(|elements| decoder.decode_aggregated_vertex_properties(&elements))
 }
 
 pub fn decode_face_properties<E>(
@@ -411,8 +1626,266 @@ where
     E: FaceElementDecoder + FacePropertyDecoder,
 {
     decoder
-        .decode_face_elements(definitions, elements)
-        .and_then(|(definition, elements)| decoder.decode_face_properties(definition, elements))
+        .aggregate_face_elements(definitions, elements)
+        .and_then(|elements| decoder.decode_aggregated_face_properties(&elements))
+}
+
+pub fn decode_edge_properties<E>(
+    decoder: &E,
+    definitions: &Header,
+    elements: &Payload,
+) -> Result<E::Output, PlyError>
+where
+    E: EdgeElementDecoder + EdgePropertyDecoder,
+{
+    decoder
+        .decode_edge_elements(definitions, elements)
+        .and_then(|elements| decoder.decode_edge_properties(&elements))
+}
+
+fn stream_surface<E, B, R>(
+    decoder: &E,
+    parser: &Parser<Element>,
+    header: &ply_rs::ply::Header,
+    read: &mut R,
+    builder: &mut B,
+) -> Result<(), PlyError>
+where
+    B: SurfaceBuilder,
+    B::Facet: FromGeometry<<E as FaceDecoder>::Face>,
+    B::Vertex: FromGeometry<<E as VertexDecoder>::Vertex>,
+    PlyError: From<B::Error>,
+    E: FaceElementDecoder + FacePropertyDecoder + VertexPropertyDecoder + VertexElementDecoder,
+    R: BufRead,
+{
+    let vertex = select_element(&header.elements, |definitions, elements| {
+        decoder.aggregate_vertex_elements(definitions, elements)
+    })?;
+    let face = select_element(&header.elements, |definitions, elements| {
+        decoder.aggregate_face_elements(definitions, elements)
+    })?;
+    let mut keys = None;
+    for definition in header.elements.values() {
+        if definition.name == vertex {
+            let mut vertices = Vec::with_capacity(definition.count);
+            read_elements(parser, header, read, definition, |elements| {
+                for (index, geometry) in decoder
+                    .decode_aggregated_vertex_properties(elements)?
+                    .into_iter()
+                    .enumerate()
+                {
+                    let key = builder
+                        .insert_vertex(geometry)
+                        .map_err(|error| elements.context(index, error.into()))?;
+                    vertices.push(key);
+                }
+                Ok(())
+            })?;
+            keys = Some(vertices);
+        }
+        else if definition.name == face {
+            // Faces cannot be inserted before the vertices that they refer to.
+            let keys = keys.as_ref().ok_or_else(|| PlyError::Encoding)?;
+            builder.facets_with(|builder| {
+                Ok::<_, B::Error>(read_elements(
+                    parser,
+                    header,
+                    read,
+                    definition,
+                    |elements| {
+                        for (index, (indices, geometry)) in decoder
+                            .decode_aggregated_face_properties(elements)?
+                            .into_iter()
+                            .enumerate()
+                        {
+                            indices
+                                .into_iter()
+                                .map(|index| keys.get(index).cloned().ok_or(PlyError::Encoding))
+                                .collect::<Result<SmallVec<[_; 4]>, _>>()
+                                .and_then(|perimeter| {
+                                    builder
+                                        .insert_facet(perimeter.as_slice(), geometry)
+                                        .map_err(PlyError::from)
+                                })
+                                .map_err(|error| elements.context(index, error))?;
+                        }
+                        Ok(())
+                    },
+                ))
+            })??;
+            return Ok(());
+        }
+        else {
+            read_elements(parser, header, read, definition, |_| Ok(()))?;
+        }
+    }
+    Err(PlyError::ElementNotFound {
+        name: if keys.is_some() { face } else { vertex },
+    })
+}
+
+// Determines which element is read by a decoder. The decoder is given an empty
+// payload with an entry for each element in the header.
+fn select_element<F>(definitions: &Header, f: F) -> Result<String, PlyError>
+where
+    F: for<'a> FnOnce(&'a Header, &'a Payload) -> Result<Elements<'a>, PlyError>,
+{
+    let elements = definitions
+        .keys()
+        .map(|key| (key.clone(), Vec::new()))
+        .collect::<Payload>();
+    let elements = f(definitions, &elements)?;
+    let mut names = elements
+        .definitions()
+        .map(|definition| definition.name.clone());
+    match (names.next(), names.next()) {
+        (Some(name), None) => Ok(name),
+        // Properties aggregated from more than one element cannot be streamed,
+        // because elements are read sequentially.
+        _ => Err(PlyError::Encoding),
+    }
+}
+
+// Reads all elements for the given definition in batches of no more than
+// `STREAM_CHUNK_SIZE` elements.
+fn read_elements<R, F>(
+    parser: &Parser<Element>,
+    header: &ply_rs::ply::Header,
+    read: &mut R,
+    definition: &ElementDefinition,
+    mut f: F,
+) -> Result<(), PlyError>
+where
+    R: BufRead,
+    F: FnMut(&Elements) -> Result<(), PlyError>,
+{
+    let mut offset = 0;
+    while offset < definition.count {
+        let mut chunk = definition.clone();
+        chunk.count = cmp::min(definition.count - offset, STREAM_CHUNK_SIZE);
+        let elements = parser.read_payload_for_element(read, &chunk, header)?;
+        let mut elements = Elements::from((definition, &elements));
+        elements.offset = offset;
+        f(&elements)?;
+        offset += chunk.count;
+    }
+    Ok(())
+}
+
+pub fn encode_elements<K>(definitions: &Header, key: K) -> Result<&ElementDefinition, PlyError>
+where
+    K: AsRef<str>,
+{
+    let key = key.as_ref();
+    definitions
+        .get(key)
+        .ok_or_else(|| PlyError::ElementNotFound {
+            name: key.to_owned(),
+        })
+}
+
+fn encode_ply<E, W>(
+    encoder: &E,
+    definitions: Header,
+    vertices: Vec<Element>,
+    faces: Vec<Element>,
+    mut write: W,
+) -> Result<usize, PlyError>
+where
+    E: FaceElementEncoder + FormatEncoder + VertexElementEncoder,
+    W: Write,
+{
+    let mut payload = Payload::new();
+    payload.insert(
+        encoder.encode_vertex_elements(&definitions)?.name.clone(),
+        vertices,
+    );
+    payload.insert(
+        encoder.encode_face_elements(&definitions)?.name.clone(),
+        faces,
+    );
+    let mut ply = ply_rs::ply::Ply::<Element>::new();
+    ply.header.encoding = encoder.format().into();
+    // Write only the elements that have been encoded. The order of elements in
+    // the header definitions is preserved.
+    for (name, mut definition) in definitions {
+        if let Some(elements) = payload.get(&name) {
+            definition.count = elements.len();
+            ply.header.elements.insert(name, definition);
+        }
+    }
+    ply.payload = payload;
+    let n = Writer::<Element>::new().write_ply(&mut write, &mut ply)?;
+    Ok(n)
+}
+
+fn num_cast_property<T>(value: T, data_type: &PropertyType) -> Result<Property, PlyError>
+where
+    T: NumCast,
+{
+    match *data_type {
+        PropertyType::Scalar(ref scalar) => Ok(match *scalar {
+            ScalarType::Char => Property::Char(num_cast_scalar(value)?),
+            ScalarType::UChar => Property::UChar(num_cast_scalar(value)?),
+            ScalarType::Short => Property::Short(num_cast_scalar(value)?),
+            ScalarType::UShort => Property::UShort(num_cast_scalar(value)?),
+            ScalarType::Int => Property::Int(num_cast_scalar(value)?),
+            ScalarType::UInt => Property::UInt(num_cast_scalar(value)?),
+            ScalarType::Float => Property::Float(num_cast_scalar(value)?),
+            ScalarType::Double => Property::Double(num_cast_scalar(value)?),
+        }),
+        _ => Err(PlyError::Encoding),
+    }
+}
+
+fn num_cast_list_property<T, I>(values: I, data_type: &PropertyType) -> Result<Property, PlyError>
+where
+    T: NumCast,
+    I: IntoIterator<Item = T>,
+{
+    let values = values.into_iter().collect::<Vec<_>>();
+    match *data_type {
+        PropertyType::List(_, ref scalar) => Ok(match *scalar {
+            ScalarType::Char => Property::ListChar(num_cast_list(values)?),
+            ScalarType::UChar => Property::ListUChar(num_cast_list(values)?),
+            ScalarType::Short => Property::ListShort(num_cast_list(values)?),
+            ScalarType::UShort => Property::ListUShort(num_cast_list(values)?),
+            ScalarType::Int => Property::ListInt(num_cast_list(values)?),
+            ScalarType::UInt => Property::ListUInt(num_cast_list(values)?),
+            ScalarType::Float => Property::ListFloat(num_cast_list(values)?),
+            ScalarType::Double => Property::ListDouble(num_cast_list(values)?),
+        }),
+        _ => Err(PlyError::Encoding),
+    }
+}
+
+// Reads face indices from the `vertex_index` property or, if it is not present,
+// the `vertex_indices` property.
+fn decode_indices(attributes: &Attributes) -> Result<(SmallVec<[usize; 4]>, ()), PlyError> {
+    let key = if attributes.contains("vertex_index") {
+        "vertex_index"
+    }
+    else {
+        "vertex_indices"
+    };
+    let indices = attributes.list(key)?;
+    Ok((indices, ()))
+}
+
+fn opaque<T>(property: &Property) -> Result<T, PlyError>
+where
+    T: NumCast,
+{
+    match *property {
+        Property::Char(_) => num_cast_scalar(i8::MAX),
+        Property::UChar(_) => num_cast_scalar(u8::MAX),
+        Property::Short(_) => num_cast_scalar(i16::MAX),
+        Property::UShort(_) => num_cast_scalar(u16::MAX),
+        Property::Int(_) => num_cast_scalar(i32::MAX),
+        Property::UInt(_) => num_cast_scalar(u32::MAX),
+        Property::Float(_) | Property::Double(_) => num_cast_scalar(1.0),
+        _ => Err(PlyError::Encoding),
+    }
 }
 
 fn num_cast_scalar<T, U>(value: T) -> Result<U, PlyError>
@@ -438,14 +1911,127 @@ where
 #[cfg(test)]
 mod tests {
     use nalgebra::Point3;
+    use typenum::U3;
 
     use crate::buffer::MeshBuffer;
-    use crate::encoding::ply::{FromPly, PositionEncoding};
-    use crate::graph::MeshGraph;
-    use crate::primitive::Tetragon;
+    use crate::encoding::ply::{
+        AttributeDecoder, Attributes, DecodeAttributes, DecodePosition, Element, ElementDefinition,
+        ElementExt, FaceElementDecoder, FacePropertyDecoder, Format, FromEdgePly, FromPly,
+        FromPlyStream, Header, PlyError, PositionEncoding, PropertyDefinition, PropertyType,
+        ScalarType, ToPly, VertexElementDecoder, VertexPropertyDecoder,
+    };
+    use crate::encoding::{FaceDecoder, VertexDecoder};
+    use crate::graph::{GraphData, MeshGraph};
+    use crate::primitive::{Tetragon, Trigon};
 
     type E3 = Point3<f64>;
 
+    const TRIANGLE: &[u8] = b"\
+        ply\n\
+        format ascii 1.0\n\
+        element vertex 3\n\
+        property float x\n\
+        property float y\n\
+        property float z\n\
+        property float nx\n\
+        property float ny\n\
+        property float nz\n\
+        property uchar red\n\
+        property uchar green\n\
+        property uchar blue\n\
+        element confidence 3\n\
+        property float value\n\
+        element face 1\n\
+        property list uchar int vertex_indices\n\
+        property list uchar float texcoord\n\
+        element material 1\n\
+        property uchar index\n\
+        end_header\n\
+        0 0 0 0 0 1 255 0 0\n\
+        1 0 0 0 0 1 0 255 0\n\
+        0 1 0 0 0 1 0 0 255\n\
+        0.5\n\
+        0.75\n\
+        1\n\
+        3 0 1 2 6 0 0 1 0 0 1\n\
+        7\n\
+    ";
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vertex {
+        position: E3,
+        normal: [f64; 3],
+        color: [u8; 4],
+        confidence: f64,
+    }
+
+    impl DecodeAttributes for Vertex {
+        fn decode_attributes(attributes: &Attributes) -> Result<Self, PlyError> {
+            Ok(Vertex {
+                position: <E3 as DecodePosition<U3>>::decode_position(attributes)?,
+                normal: attributes
+                    .normal()?
+                    .ok_or_else(|| PlyError::PropertyNotFound {
+                        name: "nx".to_owned(),
+                    })?,
+                color: attributes
+                    .color()?
+                    .ok_or_else(|| PlyError::PropertyNotFound {
+                        name: "red".to_owned(),
+                    })?,
+                confidence: attributes.scalar("value")?,
+            })
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Face {
+        material: u8,
+        arity: usize,
+    }
+
+    impl DecodeAttributes for Face {
+        fn decode_attributes(attributes: &Attributes) -> Result<Self, PlyError> {
+            let texcoord: Vec<f64> = attributes.list("texcoord")?;
+            Ok(Face {
+                material: attributes.scalar("index")?,
+                arity: texcoord.len() / 2,
+            })
+        }
+    }
+
+    impl GraphData for Vertex {
+        type Vertex = Self;
+        type Arc = ();
+        type Edge = ();
+        type Face = Face;
+    }
+
+    fn definitions() -> Header {
+        let mut vertex = ElementDefinition::new("vertex".to_owned());
+        for name in &["x", "y", "z"] {
+            vertex.properties.insert(
+                (*name).to_owned(),
+                PropertyDefinition::new(
+                    (*name).to_owned(),
+                    PropertyType::Scalar(ScalarType::Double),
+                ),
+            );
+        }
+        let mut face = ElementDefinition::new("face".to_owned());
+        face.properties.insert(
+            "vertex_index".to_owned(),
+            PropertyDefinition::new(
+                "vertex_index".to_owned(),
+                PropertyType::List(ScalarType::UChar, ScalarType::UInt),
+            ),
+        );
+        let mut definitions = Header::new();
+        definitions.insert("vertex".to_owned(), vertex);
+        definitions.insert("face".to_owned(), face);
+        definitions
+    }
+
     #[test]
     fn decode_into_buffer() {
         let buffer = {
@@ -470,4 +2056,356 @@ mod tests {
         assert_eq!(12, graph.edge_count());
         assert_eq!(6, graph.face_count());
     }
+
+    #[test]
+    fn decode_with_element_decoder() {
+        // This decoder implements only the required methods and so decodes
+        // properties from a single element.
+        struct Decoder;
+
+        impl VertexDecoder for Decoder {
+            type Output = Vec<Self::Vertex>;
+            type Vertex = E3;
+        }
+
+        impl VertexElementDecoder for Decoder {}
+
+        impl VertexPropertyDecoder for Decoder {
+            fn decode_vertex_properties<'a, I>(
+                &self,
+                _: &'a ElementDefinition,
+                elements: I,
+            ) -> Result<Self::Output, PlyError>
+            where
+                I: IntoIterator<Item = &'a Element>,
+            {
+                elements
+                    .into_iter()
+                    .map(|element| E3::decode_position(element))
+                    .collect()
+            }
+        }
+
+        impl FaceDecoder for Decoder {
+            type Output = Vec<(Self::Index, Self::Face)>;
+            type Index = Vec<usize>;
+            type Face = ();
+        }
+
+        impl FaceElementDecoder for Decoder {}
+
+        impl FacePropertyDecoder for Decoder {
+            fn decode_face_properties<'a, I>(
+                &self,
+                _: &'a ElementDefinition,
+                elements: I,
+            ) -> Result<Self::Output, PlyError>
+            where
+                I: IntoIterator<Item = &'a Element>,
+            {
+                elements
+                    .into_iter()
+                    .map(|element| Ok((element.list("vertex_indices")?, ())))
+                    .collect()
+            }
+        }
+
+        let graph = MeshGraph::<E3>::from_ply(Decoder, TRIANGLE).unwrap().0;
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+    }
+
+    #[test]
+    fn decode_vertex_indices_into_graph() {
+        let graph = MeshGraph::<E3>::from_ply(PositionEncoding::<E3>::default(), TRIANGLE)
+            .unwrap()
+            .0;
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+    }
+
+    #[test]
+    fn stream_into_buffer() {
+        let buffer = {
+            let ply: &[u8] = include_bytes!("../../../data/cube.ply");
+            MeshBuffer::<Tetragon<usize>, E3>::from_ply_stream(
+                PositionEncoding::<E3>::default(),
+                ply,
+            )
+            .unwrap()
+            .0
+        };
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        assert_eq!(6, buffer.as_index_slice().len());
+    }
+
+    #[test]
+    fn stream_into_graph() {
+        let graph = {
+            let ply: &[u8] = include_bytes!("../../../data/cube.ply");
+            MeshGraph::<E3>::from_ply(PositionEncoding::<E3>::default(), ply)
+                .unwrap()
+                .0
+        };
+        for format in &[
+            Format::Ascii,
+            Format::BinaryBigEndian,
+            Format::BinaryLittleEndian,
+        ] {
+            let mut ply = Vec::new();
+            graph
+                .to_ply(
+                    definitions(),
+                    PositionEncoding::<E3>::with_format(*format),
+                    &mut ply,
+                )
+                .unwrap();
+            let graph =
+                MeshGraph::<E3>::from_ply_stream(PositionEncoding::<E3>::default(), ply.as_slice())
+                    .unwrap()
+                    .0;
+            assert_eq!(8, graph.vertex_count());
+            assert_eq!(12, graph.edge_count());
+            assert_eq!(6, graph.face_count());
+        }
+    }
+
+    #[test]
+    fn stream_with_interleaved_elements() {
+        // The `confidence` element is read and discarded between vertices and
+        // faces.
+        let ply: &[u8] = b"\
+            ply\n\
+            format ascii 1.0\n\
+            element vertex 3\n\
+            property float x\n\
+            property float y\n\
+            property float z\n\
+            element confidence 3\n\
+            property float value\n\
+            element face 1\n\
+            property list uchar int vertex_index\n\
+            element material 1\n\
+            property uchar index\n\
+            end_header\n\
+            0 0 0\n\
+            1 0 0\n\
+            0 1 0\n\
+            0.5\n\
+            0.75\n\
+            1\n\
+            3 0 1 2\n\
+            7\n\
+        ";
+        let (graph, header) =
+            MeshGraph::<E3>::from_ply_stream(PositionEncoding::<E3>::default(), ply).unwrap();
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+        assert!(header.contains_key("material"));
+    }
+
+    #[test]
+    fn stream_with_aggregated_elements() {
+        let decoder = AttributeDecoder::<Vertex, Face>::default()
+            .with_vertex_elements(&["vertex", "confidence"])
+            .with_face_elements(&["face", "material"]);
+        assert!(MeshGraph::<Vertex>::from_ply_stream(decoder, TRIANGLE).is_err());
+    }
+
+    #[test]
+    fn encode_buffer() {
+        let buffer = {
+            let ply: &[u8] = include_bytes!("../../../data/cube.ply");
+            MeshBuffer::<Tetragon<usize>, E3>::from_ply(PositionEncoding::<E3>::default(), ply)
+                .unwrap()
+                .0
+        };
+        let mut ply = Vec::new();
+        buffer
+            .to_ply(definitions(), PositionEncoding::<E3>::default(), &mut ply)
+            .unwrap();
+        let buffer = MeshBuffer::<Tetragon<usize>, E3>::from_ply(
+            PositionEncoding::<E3>::default(),
+            ply.as_slice(),
+        )
+        .unwrap()
+        .0;
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        assert_eq!(6, buffer.as_index_slice().len());
+    }
+
+    #[test]
+    fn encode_graph() {
+        let graph = {
+            let ply: &[u8] = include_bytes!("../../../data/cube.ply");
+            MeshGraph::<E3>::from_ply(PositionEncoding::<E3>::default(), ply)
+                .unwrap()
+                .0
+        };
+        for format in &[
+            Format::Ascii,
+            Format::BinaryBigEndian,
+            Format::BinaryLittleEndian,
+        ] {
+            let mut ply = Vec::new();
+            graph
+                .to_ply(
+                    definitions(),
+                    PositionEncoding::<E3>::with_format(*format),
+                    &mut ply,
+                )
+                .unwrap();
+            let graph =
+                MeshGraph::<E3>::from_ply(PositionEncoding::<E3>::default(), ply.as_slice())
+                    .unwrap()
+                    .0;
+            assert_eq!(8, graph.vertex_count());
+            assert_eq!(12, graph.edge_count());
+            assert_eq!(6, graph.face_count());
+        }
+    }
+
+    #[test]
+    fn encode_graph_without_definition() {
+        let graph = {
+            let ply: &[u8] = include_bytes!("../../../data/cube.ply");
+            MeshGraph::<E3>::from_ply(PositionEncoding::<E3>::default(), ply)
+                .unwrap()
+                .0
+        };
+        let mut definitions = definitions();
+        definitions.remove("face");
+        let mut ply = Vec::new();
+        assert!(graph
+            .to_ply(definitions, PositionEncoding::<E3>::default(), &mut ply)
+            .is_err());
+    }
+
+    #[test]
+    fn decode_attributes_into_graph() {
+        let decoder = AttributeDecoder::<Vertex, Face>::default()
+            .with_vertex_elements(&["vertex", "confidence"])
+            .with_face_elements(&["face", "material"]);
+        let graph = MeshGraph::<Vertex>::from_ply(decoder, TRIANGLE).unwrap().0;
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+        for vertex in graph.vertices() {
+            assert_eq!([0.0, 0.0, 1.0], vertex.data.normal);
+            assert_eq!(255, vertex.data.color[3]);
+            assert!(vertex.data.confidence >= 0.5);
+        }
+        let face = graph.faces().next().unwrap();
+        assert_eq!(7, face.data.material);
+        assert_eq!(3, face.data.arity);
+    }
+
+    #[test]
+    fn decode_attributes_into_buffer() {
+        let decoder =
+            AttributeDecoder::<Vertex>::default().with_vertex_elements(&["vertex", "confidence"]);
+        let buffer = MeshBuffer::<Trigon<usize>, Vertex>::from_ply(decoder, TRIANGLE)
+            .unwrap()
+            .0;
+        assert_eq!(3, buffer.as_vertex_slice().len());
+        assert_eq!([255, 0, 0, 255], buffer.as_vertex_slice()[0].color);
+    }
+
+    #[test]
+    fn decode_attributes_with_mismatched_elements() {
+        let decoder = AttributeDecoder::<Vertex, Face>::default()
+            .with_vertex_elements(&["vertex", "material"]);
+        assert!(MeshGraph::<Vertex>::from_ply(decoder, TRIANGLE).is_err());
+    }
+
+    #[test]
+    fn decode_error_context() {
+        // Without the `confidence` element, the `value` property of the first
+        // vertex cannot be found.
+        let decoder = AttributeDecoder::<Vertex, Face>::default();
+        match MeshGraph::<Vertex>::from_ply(decoder, TRIANGLE) {
+            Err(PlyError::Decode {
+                element,
+                index,
+                source,
+            }) => {
+                assert_eq!("vertex", element);
+                assert_eq!(0, index);
+                match *source {
+                    PlyError::PropertyNotFound { name } => assert_eq!("value", name),
+                    _ => panic!("unexpected error"),
+                }
+            }
+            _ => panic!("unexpected result"),
+        }
+
+        // Missing elements are named.
+        let decoder = AttributeDecoder::<Vertex, Face>::default()
+            .with_vertex_elements(&["vertex", "confidence"])
+            .with_face_elements(&["polygon"]);
+        match MeshGraph::<Vertex>::from_ply(decoder, TRIANGLE) {
+            Err(PlyError::ElementNotFound { name }) => assert_eq!("polygon", name),
+            _ => panic!("unexpected result"),
+        }
+    }
+    #[test]
+    fn decode_edges_into_graph() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Position(E3);
+
+        impl DecodeAttributes for Position {
+            fn decode_attributes(attributes: &Attributes) -> Result<Self, PlyError> {
+                <E3 as DecodePosition<U3>>::decode_position(attributes).map(Position)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        struct Crease(f64);
+
+        impl DecodeAttributes for Crease {
+            fn decode_attributes(attributes: &Attributes) -> Result<Self, PlyError> {
+                attributes.scalar("crease").map(Crease)
+            }
+        }
+
+        struct Creased;
+
+        impl GraphData for Creased {
+            type Vertex = Position;
+            type Arc = ();
+            type Edge = Crease;
+            type Face = ();
+        }
+
+        let ply: &[u8] = b"\
+            ply\n\
+            format ascii 1.0\n\
+            element vertex 4\n\
+            property float x\n\
+            property float y\n\
+            property float z\n\
+            element face 1\n\
+            property list uchar int vertex_indices\n\
+            element edge 1\n\
+            property int vertex1\n\
+            property int vertex2\n\
+            property float crease\n\
+            end_header\n\
+            0 0 0\n\
+            1 0 0\n\
+            1 1 0\n\
+            0 1 0\n\
+            4 0 1 2 3\n\
+            2 1 0.5\n\
+        ";
+        let decoder = AttributeDecoder::<Position, (), Crease>::default();
+        let graph = MeshGraph::<Creased>::from_edge_ply(decoder, ply).unwrap().0;
+        assert_eq!(4, graph.edge_count());
+        assert_eq!(
+            1,
+            graph
+                .edges()
+                .filter(|edge| edge.data == Crease(0.5))
+                .count()
+        );
+    }
 }
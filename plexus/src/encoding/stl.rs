@@ -0,0 +1,898 @@
+//! [STL] encoding.
+//!
+//! This module provides support for the [STL] format via the [`FromStl`] and
+//! [`ToStl`] traits. These traits can be used with a decoder and encoder to
+//! read and write mesh data structures to and from the [STL] format. Both the
+//! ASCII and binary variants of the format are supported.
+//!
+//! [STL] describes unindexed triangles, so vertices are welded when decoding:
+//! coincident vertices are disambiguated by an [`Indexer`]. Triangles that are
+//! degenerate after welding (that is, triangles with coincident vertices) are
+//! discarded. [STL] only supports triangles, so faces are triangulated by ear
+//! clipping when encoding and a normal is written for each face. Normals read
+//! from [STL] data are ignored when decoding.
+//!
+//! # Examples
+//!
+//! Reading an [STL] file into a [`MeshGraph`]:
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::R64;
+//! use nalgebra::Point3;
+//! use plexus::encoding::stl::{FromStl, PositionEncoding};
+//! use plexus::graph::MeshGraph;
+//! use std::io::Read;
+//!
+//! type E3 = Point3<R64>;
+//!
+//! // Read from a file, network, etc.
+//! fn read() -> impl Read {
+//!     // ...
+//!     # let stl: &[u8] = b"\
+//!     #     solid triangle\n\
+//!     #     facet normal 0 0 1\n\
+//!     #     outer loop\n\
+//!     #     vertex 0 0 0\n\
+//!     #     vertex 1 0 0\n\
+//!     #     vertex 0 1 0\n\
+//!     #     endloop\n\
+//!     #     endfacet\n\
+//!     #     endsolid triangle\n\
+//!     # ";
+//!     # stl
+//! }
+//!
+//! let encoding = PositionEncoding::<E3>::default();
+//! let (graph, _) = MeshGraph::<E3>::from_stl(encoding, read()).unwrap();
+//! ```
+//!
+//! Writing a [`MeshGraph`] as binary [STL]:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::encoding::stl::{Format, PositionEncoding, ToStl};
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cube::Cube;
+//! use plexus::primitive::generate::Position;
+//!
+//! type E3 = Point3<f64>;
+//!
+//! let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+//!
+//! let mut stl = Vec::new();
+//! graph
+//!     .to_stl(PositionEncoding::<E3>::with_format(Format::Binary), &mut stl)
+//!     .unwrap();
+//! ```
+//!
+//! [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+//!
+//! [`FromStl`]: crate::encoding::stl::FromStl
+//! [`ToStl`]: crate::encoding::stl::ToStl
+//! [`MeshGraph`]: crate::graph::MeshGraph
+//! [`Indexer`]: crate::index::Indexer
+
+#![cfg(feature = "encoding-stl")]
+
+use num::cast;
+use num::NumCast;
+use smallvec::SmallVec;
+use std::hash::Hash;
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+use std::str::{self, FromStr};
+use theon::space::{EuclideanSpace, FiniteDimensional, Vector};
+use theon::AsPosition;
+use thiserror::Error;
+use typenum::U3;
+
+use crate::buffer::BufferError;
+use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::geometry::triangulation::{self, Planar};
+use crate::graph::{FaceNormal, GraphError, MeshGraph, VertexPosition};
+use crate::index::{HashIndexer, IndexVertices, Indexer};
+use crate::primitive::Trigon;
+
+const HEADER_LEN: usize = 80;
+const TRIANGLE_LEN: usize = 50;
+
+/// Errors concerning the [STL] encoding.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+#[derive(Debug, Error)]
+pub enum StlError {
+    /// A statement in ASCII data could not be parsed.
+    #[error("malformed statement on line {line}")]
+    Malformed {
+        /// The line on which the malformed statement begins.
+        line: usize,
+    },
+    /// The length of binary data is inconsistent with its triangle count.
+    #[error("binary data truncated")]
+    Truncated,
+    /// Data could not be converted to or from its encoding.
+    #[error("failed to convert data")]
+    Encoding,
+    #[error("failed to build mesh buffer")]
+    Buffer(#[from] BufferError),
+    #[error("failed to build mesh graph")]
+    Graph(#[from] GraphError),
+    #[error("failed to read or write data")]
+    Io(#[from] io::Error),
+}
+
+/// Variant of the [STL] format.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Format {
+    Ascii,
+    Binary,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Format::Binary
+    }
+}
+
+/// Triangle (facet) read from or written to the [STL] encoding.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Triangle {
+    pub normal: [f32; 3],
+    pub vertices: [[f32; 3]; 3],
+    /// Attribute byte count of binary data. This is typically zero and is not
+    /// written to ASCII data.
+    pub attribute: u16,
+}
+
+/// Triangles read from or written to the [STL] encoding.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stl {
+    /// Name of the solid in ASCII data or the header of binary data.
+    pub name: String,
+    pub triangles: Vec<Triangle>,
+}
+
+impl Stl {
+    /// Reads ASCII or binary [STL] data.
+    ///
+    /// Binary data is detected by its length, because binary headers may begin
+    /// with `solid` just like ASCII data.
+    ///
+    /// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+    pub fn parse<R>(mut read: R) -> Result<Self, StlError>
+    where
+        R: Read,
+    {
+        let mut data = Vec::new();
+        read.read_to_end(&mut data)?;
+        if is_binary(&data) {
+            Stl::parse_binary(&data)
+        }
+        else {
+            match str::from_utf8(&data) {
+                Ok(text) if text.trim_start().starts_with("solid") => Stl::parse_ascii(text),
+                _ => Stl::parse_binary(&data),
+            }
+        }
+    }
+
+    /// Writes [STL] data in the given format.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+    pub fn write<W>(&self, format: Format, write: W) -> Result<usize, StlError>
+    where
+        W: Write,
+    {
+        match format {
+            Format::Ascii => self.write_ascii(write),
+            Format::Binary => self.write_binary(write),
+        }
+    }
+
+    fn parse_ascii(text: &str) -> Result<Self, StlError> {
+        let mut tokens = Tokens::new(text);
+        tokens.expect("solid")?;
+        let name = tokens.rest_of_line().join(" ");
+        let mut triangles = Vec::new();
+        loop {
+            let (line, token) = tokens.next()?;
+            match token {
+                "facet" => {
+                    tokens.expect("normal")?;
+                    let normal = tokens.scalars()?;
+                    tokens.expect("outer")?;
+                    tokens.expect("loop")?;
+                    let mut vertices = [[0.0; 3]; 3];
+                    for vertex in &mut vertices {
+                        tokens.expect("vertex")?;
+                        *vertex = tokens.scalars()?;
+                    }
+                    tokens.expect("endloop")?;
+                    tokens.expect("endfacet")?;
+                    triangles.push(Triangle {
+                        normal,
+                        vertices,
+                        attribute: 0,
+                    });
+                }
+                // Ignore any name following `endsolid` and any trailing data.
+                "endsolid" => {
+                    break;
+                }
+                _ => {
+                    return Err(StlError::Malformed { line });
+                }
+            }
+        }
+        Ok(Stl { name, triangles })
+    }
+
+    fn parse_binary(data: &[u8]) -> Result<Self, StlError> {
+        if data.len() < HEADER_LEN + 4 {
+            return Err(StlError::Truncated);
+        }
+        let (header, data) = data.split_at(HEADER_LEN);
+        let (count, data) = data.split_at(4);
+        let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize;
+        if data.len() < count * TRIANGLE_LEN {
+            return Err(StlError::Truncated);
+        }
+        let name = String::from_utf8_lossy(header)
+            .trim_end_matches('\0')
+            .trim()
+            .to_owned();
+        let triangles = data
+            .chunks_exact(TRIANGLE_LEN)
+            .take(count)
+            .map(|chunk| {
+                let scalar = |index: usize| {
+                    let offset = index * 4;
+                    f32::from_le_bytes([
+                        chunk[offset],
+                        chunk[offset + 1],
+                        chunk[offset + 2],
+                        chunk[offset + 3],
+                    ])
+                };
+                let vector = |index: usize| [scalar(index), scalar(index + 1), scalar(index + 2)];
+                Triangle {
+                    normal: vector(0),
+                    vertices: [vector(3), vector(6), vector(9)],
+                    attribute: u16::from_le_bytes([chunk[48], chunk[49]]),
+                }
+            })
+            .collect();
+        Ok(Stl { name, triangles })
+    }
+
+    fn write_ascii<W>(&self, mut write: W) -> Result<usize, StlError>
+    where
+        W: Write,
+    {
+        let mut n = 0;
+        let mut line = |line: String| -> Result<(), StlError> {
+            write.write_all(line.as_bytes())?;
+            write.write_all(b"\n")?;
+            n += line.len() + 1;
+            Ok(())
+        };
+        line(format!("solid {}", self.name))?;
+        for triangle in &self.triangles {
+            let [x, y, z] = triangle.normal;
+            line(format!("facet normal {:e} {:e} {:e}", x, y, z))?;
+            line("outer loop".to_owned())?;
+            for [x, y, z] in &triangle.vertices {
+                line(format!("vertex {:e} {:e} {:e}", x, y, z))?;
+            }
+            line("endloop".to_owned())?;
+            line("endfacet".to_owned())?;
+        }
+        line(format!("endsolid {}", self.name))?;
+        Ok(n)
+    }
+
+    fn write_binary<W>(&self, mut write: W) -> Result<usize, StlError>
+    where
+        W: Write,
+    {
+        let count: u32 = cast::cast(self.triangles.len()).ok_or(StlError::Encoding)?;
+        let mut header = [0u8; HEADER_LEN];
+        for (target, source) in header.iter_mut().zip(self.name.as_bytes()) {
+            *target = *source;
+        }
+        write.write_all(&header)?;
+        write.write_all(&count.to_le_bytes())?;
+        for triangle in &self.triangles {
+            let mut chunk = [0u8; TRIANGLE_LEN];
+            let scalars = triangle
+                .normal
+                .iter()
+                .chain(triangle.vertices.iter().flat_map(|vertex| vertex.iter()));
+            for (target, scalar) in chunk.chunks_exact_mut(4).zip(scalars) {
+                target.copy_from_slice(&scalar.to_le_bytes());
+            }
+            chunk[48..].copy_from_slice(&triangle.attribute.to_le_bytes());
+            write.write_all(&chunk)?;
+        }
+        Ok(HEADER_LEN + 4 + (self.triangles.len() * TRIANGLE_LEN))
+    }
+}
+
+pub trait TriangleDecoder: FaceDecoder + VertexDecoder {
+    /// Decodes triangles, welding coincident vertices using the given
+    /// indexer.
+    fn decode_triangles<N>(
+        &self,
+        stl: &Stl,
+        indexer: N,
+    ) -> Result<
+        (
+            <Self as VertexDecoder>::Output,
+            <Self as FaceDecoder>::Output,
+        ),
+        StlError,
+    >
+    where
+        N: Indexer<Trigon<<Self as VertexDecoder>::Vertex>, <Self as VertexDecoder>::Vertex>;
+}
+
+pub trait FormatEncoder {
+    fn format(&self) -> Format;
+}
+
+pub trait TriangleEncoder<V>
+where
+    V: AsPosition,
+    V::Position: EuclideanSpace,
+{
+    fn encode_triangle(
+        &self,
+        normal: &Vector<V::Position>,
+        vertices: Trigon<&V>,
+    ) -> Result<Triangle, StlError>;
+}
+
+pub trait FromStl<E>: Sized
+where
+    E: TriangleDecoder,
+{
+    /// Reads [STL] data using the given decoder and indexer.
+    ///
+    /// Vertices are welded by the indexer. Prefer [`LruIndexer`] if vertex
+    /// data cannot be hashed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is malformed or cannot be decoded.
+    ///
+    /// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+    ///
+    /// [`LruIndexer`]: crate::index::LruIndexer
+    fn from_stl_with_indexer<R, N>(
+        decoder: E,
+        indexer: N,
+        read: R,
+    ) -> Result<(Self, Stl), StlError>
+    where
+        R: Read,
+        N: Indexer<Trigon<<E as VertexDecoder>::Vertex>, <E as VertexDecoder>::Vertex>;
+
+    /// Reads [STL] data using the given decoder.
+    ///
+    /// Vertices are welded by a [`HashIndexer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is malformed or cannot be decoded.
+    ///
+    /// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+    ///
+    /// [`HashIndexer`]: crate::index::HashIndexer
+    fn from_stl<R>(decoder: E, read: R) -> Result<(Self, Stl), StlError>
+    where
+        R: Read,
+        <E as VertexDecoder>::Vertex: Clone + Eq + Hash,
+    {
+        Self::from_stl_with_indexer(decoder, HashIndexer::default(), read)
+    }
+}
+
+impl<T, E> FromStl<E> for T
+where
+    T: FromEncoding<E>,
+    StlError: From<<T as FromEncoding<E>>::Error>,
+    E: TriangleDecoder,
+{
+    fn from_stl_with_indexer<R, N>(decoder: E, indexer: N, read: R) -> Result<(Self, Stl), StlError>
+    where
+        R: Read,
+        N: Indexer<Trigon<<E as VertexDecoder>::Vertex>, <E as VertexDecoder>::Vertex>,
+    {
+        let stl = Stl::parse(read)?;
+        let (vertices, faces) = decoder.decode_triangles(&stl, indexer)?;
+        let mesh = T::from_encoding(vertices, faces)?;
+        Ok((mesh, stl))
+    }
+}
+
+/// Conversion into [STL].
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+pub trait ToStl<E> {
+    /// Writes [STL] data using the given encoder.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if data cannot be encoded or written, such as if a
+    /// face cannot be triangulated.
+    ///
+    /// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+    fn to_stl<W>(&self, encoder: E, write: W) -> Result<usize, StlError>
+    where
+        W: Write;
+}
+
+impl<E, G> ToStl<E> for MeshGraph<G>
+where
+    E: FormatEncoder + TriangleEncoder<G::Vertex>,
+    G: FaceNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn to_stl<W>(&self, encoder: E, write: W) -> Result<usize, StlError>
+    where
+        W: Write,
+    {
+        let mut stl = Stl::default();
+        for face in self.faces() {
+            let normal = face.normal()?;
+            let vertices = face.adjacent_vertices().collect::<SmallVec<[_; 4]>>();
+            // Faces are triangulated by ear clipping, which supports concave
+            // faces. Degenerate and self-intersecting faces are rejected.
+            let points = Planar::project_planar(vertices.iter().map(|vertex| *vertex.position()))
+                .ok_or(StlError::Encoding)?;
+            let triangles =
+                triangulation::ear_clip_with(&points, |_, _| true).ok_or(StlError::Encoding)?;
+            for [a, b, c] in triangles {
+                stl.triangles.push(encoder.encode_triangle(
+                    &normal,
+                    Trigon::new(&vertices[a].data, &vertices[b].data, &vertices[c].data),
+                )?);
+            }
+        }
+        stl.write(encoder.format(), write)
+    }
+}
+
+/// Encoding of vertex positions.
+///
+/// When decoding, vertex data is read from the positions of triangles and
+/// triangles with coincident vertices are discarded. When encoding, positions
+/// are written for each triangle and its normal is written from the normal of
+/// the face from which it is formed.
+pub struct PositionEncoding<T> {
+    format: Format,
+    phantom: PhantomData<T>,
+}
+
+impl<T> PositionEncoding<T> {
+    /// Creates an encoding that writes the given format.
+    ///
+    /// This has no effect on decoding, which detects the format.
+    pub fn with_format(format: Format) -> Self {
+        PositionEncoding {
+            format,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for PositionEncoding<T> {
+    fn default() -> Self {
+        PositionEncoding::with_format(Default::default())
+    }
+}
+
+impl<T> FaceDecoder for PositionEncoding<T> {
+    type Output = Vec<(Self::Index, Self::Face)>;
+    type Index = Trigon<usize>;
+    type Face = ();
+}
+
+impl<T> VertexDecoder for PositionEncoding<T> {
+    type Output = Vec<Self::Vertex>;
+    type Vertex = T;
+}
+
+impl<T> TriangleDecoder for PositionEncoding<T>
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn decode_triangles<N>(
+        &self,
+        stl: &Stl,
+        indexer: N,
+    ) -> Result<
+        (
+            <Self as VertexDecoder>::Output,
+            <Self as FaceDecoder>::Output,
+        ),
+        StlError,
+    >
+    where
+        N: Indexer<Trigon<<Self as VertexDecoder>::Vertex>, <Self as VertexDecoder>::Vertex>,
+    {
+        let triangles = stl
+            .triangles
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = triangle.vertices;
+                Ok(Trigon::new(
+                    decode_position(a)?,
+                    decode_position(b)?,
+                    decode_position(c)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, StlError>>()?;
+        let (indices, vertices) = triangles
+            .into_iter()
+            .index_vertices::<Trigon<usize>, _>(indexer);
+        // Welding may collapse a triangle into an edge or point, which cannot
+        // be represented as a face. Such triangles are discarded along with
+        // any vertices that are referenced only by them.
+        let mut vertices = vertices.into_iter().map(Some).collect::<Vec<_>>();
+        let mut welded = Vec::with_capacity(vertices.len());
+        let mut keys = vec![None; vertices.len()];
+        let mut faces = Vec::with_capacity(indices.len());
+        for trigon in indices {
+            let [a, b, c] = trigon.into_array();
+            if a == b || b == c || c == a {
+                continue;
+            }
+            let mut index = |index: usize| {
+                *keys[index].get_or_insert_with(|| {
+                    welded.extend(vertices[index].take());
+                    welded.len() - 1
+                })
+            };
+            faces.push((Trigon::new(index(a), index(b), index(c)), ()));
+        }
+        Ok((welded, faces))
+    }
+}
+
+impl<T> FormatEncoder for PositionEncoding<T> {
+    fn format(&self) -> Format {
+        self.format
+    }
+}
+
+impl<T, V> TriangleEncoder<V> for PositionEncoding<T>
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+    V: AsPosition<Position = T>,
+{
+    fn encode_triangle(
+        &self,
+        normal: &Vector<T>,
+        vertices: Trigon<&V>,
+    ) -> Result<Triangle, StlError> {
+        let [a, b, c] = vertices.into_array();
+        Ok(Triangle {
+            normal: encode_position(&(T::origin() + *normal))?,
+            vertices: [
+                encode_position(a.as_position())?,
+                encode_position(b.as_position())?,
+                encode_position(c.as_position())?,
+            ],
+            attribute: 0,
+        })
+    }
+}
+
+struct Tokens<'a> {
+    tokens: Vec<(usize, &'a str)>,
+    index: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(text: &'a str) -> Self {
+        Tokens {
+            tokens: text
+                .lines()
+                .enumerate()
+                .flat_map(|(n, line)| line.split_whitespace().map(move |token| (n + 1, token)))
+                .collect(),
+            index: 0,
+        }
+    }
+
+    fn next(&mut self) -> Result<(usize, &'a str), StlError> {
+        match self.tokens.get(self.index) {
+            Some(token) => {
+                self.index += 1;
+                Ok(*token)
+            }
+            _ => Err(StlError::Malformed {
+                line: self.tokens.last().map_or(1, |(line, _)| *line),
+            }),
+        }
+    }
+
+    fn rest_of_line(&mut self) -> Vec<&'a str> {
+        let line = match self.index.checked_sub(1).map(|index| self.tokens[index]) {
+            Some((line, _)) => line,
+            _ => {
+                return Vec::new();
+            }
+        };
+        let mut tokens = Vec::new();
+        while let Some((n, token)) = self.tokens.get(self.index) {
+            if *n != line {
+                break;
+            }
+            tokens.push(*token);
+            self.index += 1;
+        }
+        tokens
+    }
+
+    fn expect(&mut self, keyword: &str) -> Result<(), StlError> {
+        let (line, token) = self.next()?;
+        if token == keyword {
+            Ok(())
+        }
+        else {
+            Err(StlError::Malformed { line })
+        }
+    }
+
+    fn scalars(&mut self) -> Result<[f32; 3], StlError> {
+        let mut scalars = [0.0; 3];
+        for scalar in &mut scalars {
+            let (line, token) = self.next()?;
+            *scalar = f32::from_str(token).map_err(|_| StlError::Malformed { line })?;
+        }
+        Ok(scalars)
+    }
+}
+
+fn is_binary(data: &[u8]) -> bool {
+    data.len() >= HEADER_LEN + 4 && {
+        let count = &data[HEADER_LEN..HEADER_LEN + 4];
+        let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize;
+        data.len() == HEADER_LEN + 4 + (count * TRIANGLE_LEN)
+    }
+}
+
+fn decode_position<T>(position: [f32; 3]) -> Result<T, StlError>
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let [x, y, z] = position;
+    Ok(T::from_xyz(
+        num_cast_scalar(x)?,
+        num_cast_scalar(y)?,
+        num_cast_scalar(z)?,
+    ))
+}
+
+fn encode_position<T>(position: &T) -> Result<[f32; 3], StlError>
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let (x, y, z) = position.into_xyz();
+    Ok([
+        num_cast_scalar(x)?,
+        num_cast_scalar(y)?,
+        num_cast_scalar(z)?,
+    ])
+}
+
+fn num_cast_scalar<T, U>(value: T) -> Result<U, StlError>
+where
+    T: NumCast,
+    U: NumCast,
+{
+    cast::cast(value).ok_or(StlError::Encoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::buffer::MeshBuffer3;
+    use crate::encoding::stl::{Format, FromStl, PositionEncoding, Stl, ToStl};
+    use crate::graph::MeshGraph;
+    use crate::index::LruIndexer;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::Tetragon;
+
+    type E3 = Point3<f64>;
+
+    const TETRAHEDRON: &[u8] = b"\
+        solid tetrahedron\n\
+        facet normal 0 0 -1\n\
+        outer loop\n\
+        vertex 0 0 0\n\
+        vertex 0 1 0\n\
+        vertex 1 0 0\n\
+        endloop\n\
+        endfacet\n\
+        facet normal 0 -1 0\n\
+        outer loop\n\
+        vertex 0 0 0\n\
+        vertex 1 0 0\n\
+        vertex 0 0 1\n\
+        endloop\n\
+        endfacet\n\
+        facet normal -1 0 0\n\
+        outer loop\n\
+        vertex 0 0 0\n\
+        vertex 0 0 1\n\
+        vertex 0 1 0\n\
+        endloop\n\
+        endfacet\n\
+        facet normal 0.577 0.577 0.577\n\
+        outer loop\n\
+        vertex 1 0 0\n\
+        vertex 0 1 0\n\
+        vertex 0 0 1\n\
+        endloop\n\
+        endfacet\n\
+        endsolid tetrahedron\n\
+    ";
+
+    #[test]
+    fn decode_ascii_into_graph() {
+        let (graph, stl) = MeshGraph::<Point3<R64>>::from_stl(
+            PositionEncoding::<Point3<R64>>::default(),
+            TETRAHEDRON,
+        )
+        .unwrap();
+        assert_eq!("tetrahedron", stl.name);
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(6, graph.edge_count());
+        assert_eq!(4, graph.face_count());
+    }
+
+    #[test]
+    fn decode_ascii_into_buffer_with_indexer() {
+        let buffer = MeshBuffer3::<usize, E3>::from_stl_with_indexer(
+            PositionEncoding::<E3>::default(),
+            LruIndexer::with_capacity(8),
+            TETRAHEDRON,
+        )
+        .unwrap()
+        .0;
+        assert_eq!(4, buffer.as_vertex_slice().len());
+        assert_eq!(4, buffer.as_index_slice().len());
+    }
+
+    #[test]
+    fn decode_degenerate_triangle() {
+        let stl: &[u8] = b"\
+            solid degenerate\n\
+            facet normal 0 0 1\n\
+            outer loop\n\
+            vertex 0 0 0\n\
+            vertex 1 0 0\n\
+            vertex 0 1 0\n\
+            endloop\n\
+            endfacet\n\
+            facet normal 0 0 1\n\
+            outer loop\n\
+            vertex 1 0 0\n\
+            vertex 1 0 0\n\
+            vertex 1 1 0\n\
+            endloop\n\
+            endfacet\n\
+            endsolid degenerate\n\
+        ";
+        let graph =
+            MeshGraph::<Point3<R64>>::from_stl(PositionEncoding::<Point3<R64>>::default(), stl)
+                .unwrap()
+                .0;
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+    }
+
+    #[test]
+    fn decode_malformed_error() {
+        let stl: &[u8] = b"solid\nfacet normal 0 0 1\nouter loop\nvertex 0 0\nendloop\n";
+        assert!(Stl::parse(stl).is_err());
+    }
+
+    #[test]
+    fn encode_binary_graph() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut stl = Vec::new();
+        let n = graph
+            .to_stl(
+                PositionEncoding::<E3>::with_format(Format::Binary),
+                &mut stl,
+            )
+            .unwrap();
+        assert_eq!(84 + (12 * 50), n);
+        assert_eq!(n, stl.len());
+
+        let buffer = MeshBuffer3::<usize, Point3<R64>>::from_stl(
+            PositionEncoding::<Point3<R64>>::default(),
+            stl.as_slice(),
+        )
+        .unwrap()
+        .0;
+        assert_eq!(8, buffer.as_vertex_slice().len());
+        assert_eq!(12, buffer.as_index_slice().len());
+    }
+
+    #[test]
+    fn encode_ascii_graph() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut stl = Vec::new();
+        graph
+            .to_stl(PositionEncoding::<E3>::with_format(Format::Ascii), &mut stl)
+            .unwrap();
+
+        let stl = Stl::parse(stl.as_slice()).unwrap();
+        assert_eq!(12, stl.triangles.len());
+        for triangle in &stl.triangles {
+            let [x, y, z] = triangle.normal;
+            assert!(((x * x) + (y * y) + (z * z) - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn encode_concave_face() {
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (3.0, 1.0, 0.0),
+                (0.0, 2.0, 0.0),
+                (1.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let mut stl = Vec::new();
+        graph
+            .to_stl(PositionEncoding::<E3>::with_format(Format::Ascii), &mut stl)
+            .unwrap();
+
+        // Triangles must not overlap or be inverted, so their areas are
+        // positive and sum to the area of the face.
+        let stl = Stl::parse(stl.as_slice()).unwrap();
+        assert_eq!(2, stl.triangles.len());
+        let areas = stl
+            .triangles
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = triangle.vertices;
+                (((b[0] - a[0]) * (c[1] - a[1])) - ((c[0] - a[0]) * (b[1] - a[1]))) / 2.0
+            })
+            .collect::<Vec<_>>();
+        assert!(areas.iter().all(|area| *area > 0.0));
+        assert!((areas.iter().sum::<f32>() - 2.0).abs() < 1e-6);
+    }
+}
@@ -370,6 +370,30 @@ where
         let right = rekey(right);
         (rekeying, (left, right))
     }
+
+    // Partitions entities into `n` storages by the index given by the
+    // function and maps their keys into each storage.
+    //
+    // Panics if the function gives an index that is not less than `n`.
+    pub fn partition_rekey_by<F>(self, n: usize, mut f: F) -> Vec<(Rekeying<E>, Self)>
+    where
+        E::Key: Eq + Hash,
+        E::Storage: Insert<E> + IntoIterator<Item = (InnerKey<E::Key>, E)>,
+        F: FnMut(&(E::Key, E)) -> usize,
+    {
+        let mut partitions = (0..n)
+            .map(|_| (Rekeying::<E>::default(), Self::new()))
+            .collect::<Vec<_>>();
+        for (key, entity) in self.into_inner() {
+            let item = (E::Key::from_inner(key), entity);
+            let index = f(&item);
+            assert!(index < n, "partition index out of bounds");
+            let (key, entity) = item;
+            let (rekeying, storage) = &mut partitions[index];
+            rekeying.insert(key, storage.insert(entity));
+        }
+        partitions
+    }
 }
 
 impl<E> AsStorage<E> for Storage<E>
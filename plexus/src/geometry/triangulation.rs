@@ -0,0 +1,361 @@
+//! Polygon triangulation.
+//!
+//! This module provides ear clipping of simple polygons. Polygons are first
+//! projected into a plane via [`Planar`] and are then clipped in two
+//! dimensions.
+//!
+//! [`Planar`]: crate::geometry::triangulation::Planar
+
+use num::cast;
+use num::NumCast;
+use std::cmp::Ordering;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use typenum::{NonZero, Unsigned, U2, U3};
+
+pub type Point = [f64; 2];
+
+/// Projection of points into a plane.
+///
+/// Points in two dimensions are unchanged. Points in three dimensions are
+/// projected into the plane that best fits them, such that the winding of
+/// the points is counter-clockwise in that plane.
+pub trait Planar<N>: EuclideanSpace + FiniteDimensional<N = N>
+where
+    N: NonZero + Unsigned,
+{
+    /// Projects points into a plane.
+    ///
+    /// Returns `None` if the points do not describe a plane, such as if they
+    /// are collinear, or if their scalars cannot be represented as `f64`.
+    fn project_planar<I>(points: I) -> Option<Vec<Point>>
+    where
+        I: IntoIterator<Item = Self>;
+}
+
+impl<T> Planar<U2> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    fn project_planar<I>(points: I) -> Option<Vec<Point>>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        points
+            .into_iter()
+            .map(|point| {
+                let (x, y) = point.into_xy();
+                Some([num_cast_scalar(x)?, num_cast_scalar(y)?])
+            })
+            .collect()
+    }
+}
+
+impl<T> Planar<U3> for T
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn project_planar<I>(points: I) -> Option<Vec<Point>>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let points = points
+            .into_iter()
+            .map(|point| {
+                let (x, y, z) = point.into_xyz();
+                Some([
+                    num_cast_scalar(x)?,
+                    num_cast_scalar(y)?,
+                    num_cast_scalar(z)?,
+                ])
+            })
+            .collect::<Option<Vec<[f64; 3]>>>()?;
+        // Compute the normal using Newell's method, which is robust for
+        // concave and nearly degenerate polygons.
+        let mut normal = [0.0; 3];
+        for (i, p) in points.iter().enumerate() {
+            let q = points[(i + 1) % points.len()];
+            normal[0] += (p[1] - q[1]) * (p[2] + q[2]);
+            normal[1] += (p[2] - q[2]) * (p[0] + q[0]);
+            normal[2] += (p[0] - q[0]) * (p[1] + q[1]);
+        }
+        let normal = normalize(normal)?;
+        // Construct a basis in the plane from the axis that is least aligned
+        // with the normal.
+        let axis = if normal[0].abs() <= normal[1].abs() && normal[0].abs() <= normal[2].abs() {
+            [1.0, 0.0, 0.0]
+        }
+        else if normal[1].abs() <= normal[2].abs() {
+            [0.0, 1.0, 0.0]
+        }
+        else {
+            [0.0, 0.0, 1.0]
+        };
+        let u = normalize(cross(axis, normal))?;
+        let v = cross(normal, u);
+        let origin = *points.first()?;
+        Some(
+            points
+                .into_iter()
+                .map(|point| {
+                    let point = [
+                        point[0] - origin[0],
+                        point[1] - origin[1],
+                        point[2] - origin[2],
+                    ];
+                    [dot(point, u), dot(point, v)]
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Triangulates a simple polygon via ear clipping.
+///
+/// Returns triangles as indices into the given points. The winding of each
+/// triangle is the same as the winding of the polygon. The given function
+/// determines if a diagonal between the points at two indices may be inserted
+/// and is used to reject ears.
+///
+/// Among the ears of a polygon, the ear that forms the best shaped triangle is
+/// clipped first, which avoids slivers in nearly degenerate polygons. The
+/// diagonal of the final quadrilateral is chosen such that the worst shaped of
+/// its triangles is as well shaped as possible.
+///
+/// Returns `None` if the polygon has fewer than three points, is degenerate or
+/// self-intersecting, or if no ear can be clipped.
+pub(crate) fn ear_clip_with<F>(points: &[Point], mut f: F) -> Option<Vec<[usize; 3]>>
+where
+    F: FnMut(usize, usize) -> bool,
+{
+    if points.len() < 3 {
+        return None;
+    }
+    // Determine the winding of the polygon via its signed area and a tolerance
+    // for orientation tests relative to the extent of the polygon.
+    let area = points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let q = points[(i + 1) % points.len()];
+            (p[0] * q[1]) - (q[0] * p[1])
+        })
+        .sum::<f64>()
+        * 0.5;
+    let (min, max) = points.iter().fold(
+        ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]),
+        |(min, max), p| {
+            (
+                [min[0].min(p[0]), min[1].min(p[1])],
+                [max[0].max(p[0]), max[1].max(p[1])],
+            )
+        },
+    );
+    let extent = (max[0] - min[0]).max(max[1] - min[1]);
+    let epsilon = extent * extent * 1e-12;
+    if !area.is_finite() || area.abs() <= epsilon {
+        return None;
+    }
+    let winding = area.signum();
+    let orientation =
+        |a: usize, b: usize, c: usize| winding * determinant(points[a], points[b], points[c]);
+    let mut polygon = (0..points.len()).collect::<Vec<_>>();
+    let mut triangles = Vec::with_capacity(points.len() - 2);
+    while polygon.len() > 4 {
+        let n = polygon.len();
+        let mut ear = None;
+        for i in 0..n {
+            let (a, b, c) = (polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]);
+            // Reflex and collinear vertices are not ears.
+            if orientation(a, b, c) <= epsilon {
+                continue;
+            }
+            // Any other vertex in or on the triangle prevents clipping the ear.
+            let is_blocked = polygon.iter().cloned().any(|d| {
+                d != a
+                    && d != b
+                    && d != c
+                    && points[d] != points[a]
+                    && points[d] != points[c]
+                    && orientation(a, b, d) >= -epsilon
+                    && orientation(b, c, d) >= -epsilon
+                    && orientation(c, a, d) >= -epsilon
+            });
+            if is_blocked || !f(c, a) {
+                continue;
+            }
+            let quality = quality(points[a], points[b], points[c]);
+            if ear.map_or(true, |(_, best)| quality > best) {
+                ear = Some((i, quality));
+            }
+        }
+        let (i, _) = ear?;
+        let n = polygon.len();
+        triangles.push([polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]]);
+        polygon.remove(i);
+    }
+    if polygon.len() == 4 {
+        let (split, _) = [0usize, 1]
+            .iter()
+            .cloned()
+            .filter_map(|offset| {
+                let [a, b, c, d] = [
+                    polygon[offset],
+                    polygon[offset + 1],
+                    polygon[offset + 2],
+                    polygon[(offset + 3) % 4],
+                ];
+                // Both triangles must have the winding of the polygon, which
+                // is not the case if the diagonal lies outside of the polygon.
+                if orientation(a, b, c) > epsilon && orientation(c, d, a) > epsilon && f(c, a) {
+                    let quality = quality(points[a], points[b], points[c])
+                        .min(quality(points[c], points[d], points[a]));
+                    Some(([[a, b, c], [c, d, a]], quality))
+                }
+                else {
+                    None
+                }
+            })
+            .max_by(|(_, p), (_, q)| p.partial_cmp(q).unwrap_or(Ordering::Equal))?;
+        triangles.extend(split.iter().cloned());
+    }
+    else {
+        if orientation(polygon[0], polygon[1], polygon[2]) <= epsilon {
+            return None;
+        }
+        triangles.push([polygon[0], polygon[1], polygon[2]]);
+    }
+    Some(triangles)
+}
+
+// Gets the twice signed area of a triangle. This is positive if the triangle
+// is wound counter-clockwise.
+fn determinant(a: Point, b: Point, c: Point) -> f64 {
+    ((b[0] - a[0]) * (c[1] - a[1])) - ((b[1] - a[1]) * (c[0] - a[0]))
+}
+
+// Gets the shape quality of a triangle in $[0,1]$, where one is an equilateral
+// triangle and zero is a degenerate triangle.
+fn quality(a: Point, b: Point, c: Point) -> f64 {
+    let length =
+        |p: Point, q: Point| ((q[0] - p[0]) * (q[0] - p[0])) + ((q[1] - p[1]) * (q[1] - p[1]));
+    let sum = length(a, b) + length(b, c) + length(c, a);
+    if sum > 0.0 {
+        (2.0 * 3.0f64.sqrt() * determinant(a, b, c).abs()) / sum
+    }
+    else {
+        0.0
+    }
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    (a[0] * b[0]) + (a[1] * b[1]) + (a[2] * b[2])
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        (a[1] * b[2]) - (a[2] * b[1]),
+        (a[2] * b[0]) - (a[0] * b[2]),
+        (a[0] * b[1]) - (a[1] * b[0]),
+    ]
+}
+
+fn normalize(a: [f64; 3]) -> Option<[f64; 3]> {
+    let magnitude = dot(a, a).sqrt();
+    if magnitude > 0.0 && magnitude.is_finite() {
+        Some([a[0] / magnitude, a[1] / magnitude, a[2] / magnitude])
+    }
+    else {
+        None
+    }
+}
+
+fn num_cast_scalar<T, U>(value: T) -> Option<U>
+where
+    T: NumCast,
+    U: NumCast,
+{
+    cast::cast(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ear_clip_concave() {
+        // A concave quadrilateral (dart) with a reflex vertex at index 3.
+        let points = [[0.0, 0.0], [3.0, 1.0], [0.0, 2.0], [1.0, 1.0]];
+        let triangles = ear_clip_with(&points, |_, _| true).unwrap();
+
+        assert_eq!(2, triangles.len());
+        // Both triangles must share the diagonal between the reflex vertex
+        // and its opposing vertex.
+        for triangle in triangles {
+            assert!(triangle.contains(&1) && triangle.contains(&3));
+            let [a, b, c] = triangle;
+            assert!(determinant(points[a], points[b], points[c]) > 0.0);
+        }
+    }
+
+    #[test]
+    fn ear_clip_collinear() {
+        // A square with collinear points along its edges.
+        let points = [
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 1.0],
+            [2.0, 2.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+            [0.0, 1.0],
+        ];
+        let triangles = ear_clip_with(&points, |_, _| true).unwrap();
+
+        assert_eq!(6, triangles.len());
+        for [a, b, c] in triangles {
+            assert!(determinant(points[a], points[b], points[c]) > 0.0);
+        }
+    }
+
+    #[test]
+    fn ear_clip_clockwise() {
+        let points = [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]];
+        let triangles = ear_clip_with(&points, |_, _| true).unwrap();
+
+        assert_eq!(2, triangles.len());
+        for [a, b, c] in triangles {
+            assert!(determinant(points[a], points[b], points[c]) < 0.0);
+        }
+    }
+
+    #[test]
+    fn ear_clip_quadrilateral_diagonal() {
+        // A sheared quadrilateral for which the diagonal between the vertices
+        // at indices 0 and 2 forms poorly shaped triangles.
+        let points = [[0.0, 0.0], [4.0, 0.0], [5.0, 1.0], [1.0, 1.0]];
+        let triangles = ear_clip_with(&points, |_, _| true).unwrap();
+
+        for triangle in triangles {
+            assert!(triangle.contains(&1) && triangle.contains(&3));
+        }
+    }
+
+    #[test]
+    fn ear_clip_degenerate() {
+        let points = [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]];
+
+        assert!(ear_clip_with(&points, |_, _| true).is_none());
+    }
+
+    #[test]
+    fn ear_clip_rejected_diagonals() {
+        let points = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let triangles =
+            ear_clip_with(&points, |a, b| !((a == 0 && b == 2) || (a == 2 && b == 0))).unwrap();
+
+        for triangle in triangles {
+            assert!(triangle.contains(&1) && triangle.contains(&3));
+        }
+    }
+}
@@ -1,8 +1,10 @@
 use arrayvec::ArrayVec;
 use derivative::Derivative;
 use fool::BoolExt;
+use num::{One, Zero};
 use slotmap::DefaultKey;
 use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::mem;
 use std::ops::{Deref, DerefMut};
@@ -17,13 +19,15 @@ use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView, Ring};
 use crate::graph::geometry::{ArcNormal, EdgeMidpoint, VertexPosition};
 use crate::graph::mutation::edge::{
-    self, ArcBridgeCache, ArcExtrudeCache, EdgeRemoveCache, EdgeSplitCache,
+    self, ArcBridgeCache, ArcExtrudeCache, EdgeBevelCache, EdgeCollapseCache, EdgeFlipCache,
+    EdgeRemoveCache, EdgeRingCutCache, EdgeSplitCache,
 };
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
 use crate::graph::{GraphError, OptionExt as _, ResultExt as _, Selector};
 use crate::transact::{Mutate, Transact};
+use crate::DynamicArity;
 
 pub trait ToArc<B>: Sized
 where
@@ -542,6 +546,214 @@ where
     }
 }
 
+impl<B, M, G> ArcView<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Converts the arc into its edge loop.
+    ///
+    /// An edge loop continues through vertices with four edges that are only
+    /// adjacent to quadrilaterals by passing to the arc on the opposite side
+    /// of the vertex. The loop extends from both vertices of the arc and stops
+    /// at poles, triangles and other polygons, and boundaries. The path is
+    /// closed if the loop returns to the arc.
+    ///
+    /// The returned path includes the arc and has the same direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = UvSphere::new(8, 4).polygons::<Position<E3>>().collect();
+    /// // Find an arc along the equator, where all faces are quadrilaterals.
+    /// let arc = graph
+    ///     .arcs()
+    ///     .find(|arc| {
+    ///         arc.adjacent_vertices()
+    ///             .all(|vertex| vertex.adjacent_faces().all(|face| face.arity() == 4))
+    ///     })
+    ///     .unwrap();
+    /// let path = arc.edge_loop();
+    ///
+    /// assert!(path.is_closed());
+    /// assert_eq!(8, path.arcs().count());
+    /// ```
+    pub fn into_edge_loop(self) -> Path<B> {
+        let keys = {
+            let arc = self.to_ref();
+            let (a, b) = arc.key().into();
+            let mut keys = vec![a, b];
+            let mut visited = keys.iter().cloned().collect::<HashSet<_>>();
+            let mut is_closed = false;
+            let mut previous = arc;
+            while let Some(next) = next_loop_arc(previous) {
+                let (_, c) = next.key().into();
+                if c == a {
+                    is_closed = true;
+                    keys.push(a);
+                    break;
+                }
+                if !visited.insert(c) {
+                    break;
+                }
+                keys.push(c);
+                previous = next;
+            }
+            if !is_closed {
+                // Extend the loop from the source vertex of the arc.
+                let mut extension = Vec::new();
+                let mut previous = arc.into_opposite_arc();
+                while let Some(next) = next_loop_arc(previous) {
+                    let (_, c) = next.key().into();
+                    if !visited.insert(c) {
+                        break;
+                    }
+                    extension.push(c);
+                    previous = next;
+                }
+                keys = extension.into_iter().rev().chain(keys).collect();
+            }
+            keys
+        };
+        let (storage, _) = self.unbind();
+        Path::bind(storage, keys).expect_consistent()
+    }
+
+    /// Gets the edge loop of the arc.
+    ///
+    /// See [`ArcView::into_edge_loop`].
+    ///
+    /// [`ArcView::into_edge_loop`]: crate::graph::ArcView::into_edge_loop
+    pub fn edge_loop(&self) -> Path<&M> {
+        self.to_ref().into_edge_loop()
+    }
+
+    // Gets the arcs that cross the edge ring of the arc. Each arc is oriented
+    // such that its face is the next quadrilateral in the ring and the first
+    // arc is the initiating arc or its opposite.
+    //
+    // Returns the arcs and whether or not the ring is closed.
+    pub(in crate::graph) fn edge_ring_arcs(&self) -> (Vec<ArcKey>, bool) {
+        let arc = self.to_ref();
+        let mut keys = vec![arc.key()];
+        let mut visited = [arc.key(), arc.key().into_opposite()]
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>();
+        let mut previous = arc;
+        while let Some(next) = next_ring_arc(previous) {
+            if next.key() == arc.key() {
+                return (keys, true);
+            }
+            if !visited.insert(next.key()) || !visited.insert(next.key().into_opposite()) {
+                break;
+            }
+            keys.push(next.key());
+            previous = next;
+        }
+        // Extend the ring across the face of the opposite arc.
+        let mut extension = Vec::new();
+        let mut previous = arc.into_opposite_arc();
+        while let Some(next) = next_ring_arc(previous) {
+            if !visited.insert(next.key()) || !visited.insert(next.key().into_opposite()) {
+                break;
+            }
+            extension.push(next.key().into_opposite());
+            previous = next;
+        }
+        (extension.into_iter().rev().chain(keys).collect(), false)
+    }
+}
+
+impl<'a, B, M, G> ArcView<B>
+where
+    B: ReborrowInto<'a, Target = M>,
+    M: 'a
+        + AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Converts the arc into an iterator over the edges of its edge ring.
+    ///
+    /// An edge ring continues across quadrilaterals by passing to the edge on
+    /// the opposite side of each face. The ring extends across both faces of
+    /// the arc and stops at triangles and other polygons and boundaries. The
+    /// edges are ordered across the ring and include the edge of the arc.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let arc = graph.arcs().nth(0).unwrap();
+    ///
+    /// // Edge rings on a cube wrap around four faces.
+    /// assert_eq!(4, arc.edge_ring().count());
+    /// ```
+    pub fn into_edge_ring(self) -> impl Clone + ExactSizeIterator<Item = EdgeView<&'a M>> {
+        let arc = self.into_ref();
+        let (keys, _) = arc.edge_ring_arcs();
+        let (storage, _) = arc.unbind();
+        keys.into_iter()
+            .map(move |ab| ArcView::bind(storage, ab).expect_consistent().into_edge())
+    }
+}
+
+impl<B, G> ArcView<B>
+where
+    B: Reborrow,
+    B::Target: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Gets an iterator of views over the edges of the edge ring of the arc.
+    ///
+    /// See [`ArcView::into_edge_ring`].
+    ///
+    /// [`ArcView::into_edge_ring`]: crate::graph::ArcView::into_edge_ring
+    pub fn edge_ring(&self) -> impl Clone + ExactSizeIterator<Item = EdgeView<&B::Target>> {
+        self.to_ref().into_edge_ring()
+    }
+}
+
 impl<'a, M, G> ArcView<&'a mut M>
 where
     M: AsStorage<Arc<G>> + AsStorageMut<Vertex<G>> + Consistent + Parametric<Data = G>,
@@ -703,6 +915,179 @@ where
         })
     }
 
+    /// Collapses the composite edge of the arc into a single vertex.
+    ///
+    /// Collapsing an arc $\overrightarrow{AB}$ merges the destination vertex
+    /// $B$ into the source vertex $A$ and replaces the data of $A$ with data
+    /// provided by the given function. Triangles adjacent to the edge
+    /// degenerate and are removed and the remaining edges of such triangles
+    /// are joined. Other faces adjacent to the edge lose a vertex.
+    ///
+    /// Returns the merged vertex $A$.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collapse would break the manifold topology of
+    /// the graph. This occurs if $A$ and $B$ are adjacent to any common vertex
+    /// other than those opposite the edge in adjacent triangles (the _link
+    /// condition_), if $A$ and $B$ are both on boundaries but the edge is not,
+    /// or if the collapse would remove all faces adjacent to $A$ and $B$.
+    ///
+    /// # Examples
+    ///
+    /// Collapsing a boundary edge of a quadrilateral:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph.arcs().nth(0).unwrap().key();
+    /// let vertex = graph
+    ///     .arc_mut(key)
+    ///     .unwrap()
+    ///     .collapse_with(|| Point2::new(0.5, 0.5))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(3, graph.vertex_count());
+    /// assert_eq!(3, graph.faces().nth(0).unwrap().arity());
+    /// ```
+    pub fn collapse_with<F>(self, f: F) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        F: FnOnce() -> G::Vertex,
+    {
+        let cache = EdgeCollapseCache::from_arc(self.to_ref())?;
+        let (storage, _) = self.unbind();
+        Ok(Mutation::replace(storage, Default::default())
+            .commit_with(|mutation| edge::collapse_with(mutation, cache, f))
+            .map(|(storage, a)| Bind::bind(storage, a).expect_consistent())
+            .expect_consistent())
+    }
+
+    /// Collapses the composite edge of the arc into a single vertex at its
+    /// midpoint.
+    ///
+    /// Collapsing an arc $\overrightarrow{AB}$ merges the destination vertex
+    /// $B$ into the source vertex $A$. The data of $A$ is retained but modified
+    /// such that the position of the vertex is the computed midpoint of $A$
+    /// and $B$. See [`collapse_with`].
+    ///
+    /// This function is only available if a [`MeshGraph`] exposes positional
+    /// data in its vertices and that data supports interpolation. See the
+    /// [`EdgeMidpoint`] trait.
+    ///
+    /// Returns the merged vertex $A$.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collapse would break the manifold topology of
+    /// the graph. See [`collapse_with`].
+    ///
+    /// # Examples
+    ///
+    /// Collapsing an edge of a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.arcs().nth(0).unwrap().key();
+    /// graph.arc_mut(key).unwrap().collapse_at_midpoint().unwrap();
+    ///
+    /// assert_eq!(7, graph.vertex_count());
+    /// assert_eq!(11, graph.edge_count());
+    /// ```
+    ///
+    /// [`collapse_with`]: crate::graph::ArcView::collapse_with
+    /// [`EdgeMidpoint`]: crate::graph::EdgeMidpoint
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    pub fn collapse_at_midpoint(self) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        G: EdgeMidpoint,
+        G::Vertex: AsPositionMut,
+    {
+        let mut geometry = self.source_vertex().data;
+        let midpoint = self.midpoint();
+        self.collapse_with(move || {
+            *geometry.as_position_mut() = midpoint;
+            geometry
+        })
+    }
+
+    /// Flips the composite edge of the arc.
+    ///
+    /// Flipping an arc $\overrightarrow{AB}$ with adjacent triangles $ABC$ and
+    /// $BAD$ replaces the edge with an edge between the opposing vertices $C$
+    /// and $D$, forming the triangles $CAD$ and $DBC$. The data of the faces
+    /// and of the arcs and edge is retained.
+    ///
+    /// Returns the flipped arc $\overrightarrow{DC}$.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc or its opposite arc is a boundary arc, if
+    /// the faces adjacent to the arc are not triangles, or if an edge already
+    /// exists between the opposing vertices.
+    ///
+    /// # Examples
+    ///
+    /// Flipping the diagonal of a quadrilateral formed from two triangles:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph
+    ///     .arcs()
+    ///     .find(|arc| !arc.edge().is_boundary_edge())
+    ///     .unwrap()
+    ///     .key();
+    /// let arc = graph.arc_mut(key).unwrap().flip().unwrap().into_ref();
+    ///
+    /// assert!(arc.adjacent_vertices().all(|vertex| {
+    ///     let position = vertex.position();
+    ///     position.x + position.y == 1.0
+    /// }));
+    /// ```
+    pub fn flip(self) -> Result<ArcView<&'a mut M>, GraphError> {
+        let cache = EdgeFlipCache::from_arc(self.to_ref())?;
+        let (storage, _) = self.unbind();
+        Ok(Mutation::replace(storage, Default::default())
+            .commit_with(|mutation| edge::flip(mutation, cache))
+            .map(|(storage, dc)| Bind::bind(storage, dc).expect_consistent())
+            .expect_consistent())
+    }
+
     // TODO: What if an edge in the bridging quadrilateral is collapsed, such as
     //       bridging arcs within a triangular ring? Document these edge cases
     //       (no pun intended).
@@ -1314,6 +1699,83 @@ where
     }
 }
 
+impl<'a, M, G> EdgeView<&'a mut M>
+where
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Default
+        + Mutable<Data = G>,
+    G: GraphData,
+{
+    /// Bevels the edge.
+    ///
+    /// Beveling replaces the edge with a strip face. The corners of this face
+    /// are placed at the given offset along the edges that are adjacent to the
+    /// beveled edge in its faces. If a vertex of the edge has more than three
+    /// edges, then a face is also inserted at that end of the strip. Vertices
+    /// of the edge are removed and all other faces adjacent to these vertices
+    /// are cut by edges between corners.
+    ///
+    /// Returns the strip face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the edge is a boundary edge, if the adjacent faces
+    /// of its vertices do not form fans about the edge, if the faces formed
+    /// from corners would be degenerate or conflict with each other, or if the
+    /// offset is not positive and less than the length of the edges along which
+    /// corners are placed. The graph is not modified if an error occurs.
+    ///
+    /// # Examples
+    ///
+    /// Beveling an edge of a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new()
+    ///     .polygons::<Position<E3>>()
+    ///     .collect();
+    /// let key = graph.edges().nth(0).unwrap().key();
+    /// let face = graph.edge_mut(key).unwrap().bevel(0.25).unwrap();
+    ///
+    /// assert_eq!(4, face.arity());
+    /// assert_eq!(10, graph.vertex_count());
+    /// assert_eq!(7, graph.face_count());
+    /// ```
+    pub fn bevel<T>(self, offset: T) -> Result<FaceView<&'a mut M>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let offset = offset.into();
+        let arc = self.arc();
+        let cache = EdgeBevelCache::from_arc(arc)?;
+        let (a, b) = (arc.source_vertex(), arc.destination_vertex());
+        let mut corners = a.corners(offset, &[b.key()])?;
+        corners.extend(b.corners(offset, &[a.key()])?);
+        let (storage, _) = self.unbind();
+        Ok(Mutation::replace(storage, Default::default())
+            .commit_with(|mutation| edge::bevel_with(mutation, cache, |a, b| corners[&(a, b)]))
+            .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
+            .expect_consistent())
+    }
+}
+
 impl<B> Borrow<EdgeKey> for EdgeView<B>
 where
     B: Reborrow,
@@ -1757,17 +2219,107 @@ where
     }
 }
 
+// Cuts a loop across the edge ring of an arc. This is exposed via
+// `MeshGraph::cut_loop`.
+//
+// Returns the storage and the keys of the vertices along the loop.
+pub(in crate::graph) fn cut_loop<'a, M, G>(
+    storage: &'a mut M,
+    ab: ArcKey,
+    t: Scalar<VertexPosition<G>>,
+) -> Result<(&'a mut M, Vec<VertexKey>), GraphError>
+where
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Default
+        + Mutable<Data = G>,
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+{
+    // Vertices must be inserted strictly within their edges.
+    if t <= Zero::zero() || t >= One::one() {
+        return Err(GraphError::Geometry);
+    }
+    let (cache, data) = {
+        let storage = &*storage;
+        let arc = ArcView::bind(storage, ab).ok_or_else(|| GraphError::TopologyNotFound)?;
+        let cache = EdgeRingCutCache::from_arc(arc.to_ref())?;
+        // Arcs in the ring share an orientation, so the vertices are placed
+        // at the same parametric position from the same side of the ring.
+        let (keys, _) = arc.edge_ring_arcs();
+        let data = keys
+            .into_iter()
+            .map(|ab| {
+                let arc = ArcView::bind(storage, ab).expect_consistent();
+                let source = arc.source_vertex();
+                let a = *source.position();
+                let b = *arc.destination_vertex().position();
+                (ab, source.data.map_position(|_| a + ((b - a) * t)))
+            })
+            .collect::<HashMap<_, _>>();
+        (cache, data)
+    };
+    Ok(Mutation::replace(storage, Default::default())
+        .commit_with(|mutation| edge::cut_ring_with(mutation, cache, |ab| data[&ab]))
+        .expect_consistent())
+}
+
+// Gets the arc that continues an edge loop through the destination vertex of
+// the given arc. Edge loops only continue through interior vertices with four
+// edges that are adjacent to quadrilaterals.
+fn next_loop_arc<'a, M, G>(arc: ArcView<&'a M>) -> Option<ArcView<&'a M>>
+where
+    M: AsStorage<Arc<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    let vertex = arc.into_destination_vertex();
+    let is_regular = vertex.valence() == 4
+        && vertex
+            .incoming_arcs()
+            .all(|arc| !arc.is_boundary_arc() && !arc.opposite_arc().is_boundary_arc())
+        && vertex.adjacent_faces().all(|face| face.arity() == 4);
+    if is_regular {
+        Some(arc.into_next_arc().into_opposite_arc().into_next_arc())
+    }
+    else {
+        None
+    }
+}
+
+// Gets the arc that continues an edge ring across the face of the given arc.
+// Edge rings only continue across quadrilaterals.
+fn next_ring_arc<'a, M, G>(arc: ArcView<&'a M>) -> Option<ArcView<&'a M>>
+where
+    M: AsStorage<Arc<G>> + AsStorage<Face<G>> + Consistent + Parametric<Data = G>,
+    G: GraphData,
+{
+    if arc.face().map_or(false, |face| face.arity() == 4) {
+        Some(arc.into_next_arc().into_next_arc().into_opposite_arc())
+    }
+    else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
 
     use crate::geometry::FromGeometry;
-    use crate::graph::{ArcKey, GraphData, MeshGraph};
+    use crate::graph::{ArcKey, GraphData, GraphError, MeshGraph, VertexView};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
     use crate::primitive::Tetragon;
 
     type E2 = Point2<R64>;
@@ -1864,6 +2416,370 @@ mod tests {
         );
     }
 
+    #[test]
+    fn collapse_edge() {
+        // Construct a fan of triangles about a vertex at the center of a
+        // square.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4],
+            vec![
+                (-1.0, -1.0), // 0
+                (1.0, -1.0),  // 1
+                (1.0, 1.0),   // 2
+                (-1.0, 1.0),  // 3
+                (0.0, 0.0),   // 4
+            ],
+            3,
+        )
+        .unwrap();
+
+        // Collapse a corner into the center. Both triangles adjacent to the
+        // edge are removed.
+        let ab = find_arc(&graph, ((0.0, 0.0), (-1.0, -1.0))).unwrap();
+        let vertex = graph
+            .arc_mut(ab)
+            .unwrap()
+            .collapse_at_midpoint()
+            .unwrap()
+            .into_ref();
+
+        assert_eq!(
+            E2::new(R64::from(-0.5), R64::from(-0.5)),
+            *vertex.position()
+        );
+        assert_eq!(2, vertex.adjacent_faces().count());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+    }
+
+    #[test]
+    fn collapse_edge_link_condition() {
+        // Construct a graph with two connected quadrilaterals.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3, 0, 3, 4, 5],
+            vec![
+                (0.0, 0.0),  // 0
+                (1.0, 0.0),  // 1
+                (1.0, 1.0),  // 2
+                (0.0, 1.0),  // 3
+                (-1.0, 1.0), // 4
+                (-1.0, 0.0), // 5
+            ],
+            4,
+        )
+        .unwrap();
+
+        // The vertices of the edge joining the quadrilaterals are both on the
+        // boundary, so collapsing the edge would pinch the graph.
+        let ab = find_arc(&graph, ((0.0, 0.0), (0.0, 1.0))).unwrap();
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph
+                .arc_mut(ab)
+                .unwrap()
+                .collapse_at_midpoint()
+                .err()
+                .unwrap()
+        );
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(2, graph.face_count());
+    }
+
+    #[test]
+    fn bevel_edge() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.edges().nth(0).unwrap().key();
+        let face = graph.edge_mut(key).unwrap().bevel(0.25).unwrap();
+
+        // Each vertex of the edge has three edges, so no faces are inserted at
+        // the ends of the strip.
+        assert_eq!(4, face.arity());
+        assert_eq!(4, face.adjacent_faces().count());
+        assert_eq!(10, graph.vertex_count());
+        assert_eq!(15, graph.edge_count());
+        assert_eq!(7, graph.face_count());
+    }
+
+    #[test]
+    fn bevel_edge_with_ends() {
+        // Construct a grid of quadrilaterals with four columns and three rows
+        // of vertices.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            (0..2usize)
+                .flat_map(|y| (0..3).map(move |x| y * 4 + x))
+                .map(|n| Tetragon::new(n, n + 1, n + 5, n + 4))
+                .collect::<Vec<_>>(),
+            (0..3)
+                .flat_map(|y| (0..4).map(move |x| (f64::from(x), f64::from(y), 0.0)))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let ab = find_arc(&graph, ((1.0, 1.0, 0.0), (2.0, 1.0, 0.0))).unwrap();
+        let face = graph.arc_mut(ab).unwrap().into_edge().bevel(0.25).unwrap();
+
+        assert_eq!(4, face.arity());
+        assert!(face
+            .adjacent_vertices()
+            .all(|vertex| ((vertex.position().y - 1.0).abs() - 0.25).abs() < 1e-9));
+        // Faces with three corners are inserted at each end of the strip.
+        assert!(face
+            .adjacent_faces()
+            .any(|face| face.arity() == 3 && face.centroid().x < 1.0));
+        assert_eq!(16, graph.vertex_count());
+        assert_eq!(9, graph.face_count());
+    }
+
+    #[test]
+    fn bevel_boundary_edge_error() {
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+            4,
+        )
+        .unwrap();
+        let key = graph.edges().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::TopologyNotFound,
+            graph.edge_mut(key).unwrap().bevel(0.25).err().unwrap()
+        );
+        assert_eq!(4, graph.vertex_count());
+    }
+
+    #[test]
+    fn bevel_degenerate_edge_error() {
+        // Construct a closed graph from two triangles with the same vertices.
+        // The corners of a strip face would coincide.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 1, 0, 2],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+            3,
+        )
+        .unwrap();
+        let key = graph.edges().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph.edge_mut(key).unwrap().bevel(0.25).err().unwrap()
+        );
+        // The graph is not modified.
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(3, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+    }
+
+    #[test]
+    fn edge_loop() {
+        // Construct a grid of quadrilaterals with four rows and columns of
+        // vertices.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            (0..3usize)
+                .flat_map(|y| (0..3).map(move |x| y * 4 + x))
+                .map(|n| Tetragon::new(n, n + 1, n + 5, n + 4))
+                .collect::<Vec<_>>(),
+            (0..4)
+                .flat_map(|y| (0..4).map(move |x| (f64::from(x), f64::from(y))))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let ab = find_arc(&graph, ((1.0, 1.0), (2.0, 1.0))).unwrap();
+        let path = graph.arc(ab).unwrap().edge_loop();
+
+        // The loop stops at boundaries.
+        assert!(path.is_open());
+        assert_eq!(
+            vec![(0.0, 1.0), (1.0, 1.0), (2.0, 1.0), (3.0, 1.0)],
+            path.vertices()
+                .map(|vertex| (vertex.position().x, vertex.position().y))
+                .collect::<Vec<_>>()
+        );
+        assert!(path.arcs().any(|arc| arc.key() == ab));
+
+        // Vertices on a boundary stop the loop immediately.
+        let ab = find_arc(&graph, ((1.0, 0.0), (2.0, 0.0))).unwrap();
+        assert_eq!(1, graph.arc(ab).unwrap().edge_loop().arcs().count());
+    }
+
+    #[test]
+    fn edge_loop_pole() {
+        let graph: MeshGraph<E3> = UvSphere::new(8, 4).polygons::<Position<E3>>().collect();
+        let is_quadrilateral =
+            |vertex: VertexView<_>| vertex.adjacent_faces().all(|face| face.arity() == 4);
+        // Find a meridian arc from a vertex adjacent to triangles at a pole to
+        // a vertex on the equator.
+        let arc = graph
+            .arcs()
+            .find(|arc| {
+                !is_quadrilateral(arc.into_source_vertex())
+                    && is_quadrilateral(arc.into_destination_vertex())
+            })
+            .unwrap();
+        let path = arc.edge_loop();
+
+        // The loop continues through the equator and stops before the poles.
+        assert!(path.is_open());
+        assert_eq!(2, path.arcs().count());
+    }
+
+    #[test]
+    fn edge_ring() {
+        let graph: MeshGraph<E3> = UvSphere::new(8, 4).polygons::<Position<E3>>().collect();
+        let is_quadrilateral =
+            |vertex: VertexView<_>| vertex.adjacent_faces().all(|face| face.arity() == 4);
+
+        // Rings along meridians stop at the triangles about the poles.
+        let arc = graph
+            .arcs()
+            .find(|arc| {
+                is_quadrilateral(arc.into_source_vertex())
+                    && is_quadrilateral(arc.into_destination_vertex())
+            })
+            .unwrap();
+        assert_eq!(3, arc.edge_ring().count());
+        assert!(arc.edge_ring().any(|edge| edge.key() == arc.edge().key()));
+
+        // Rings along latitudes are closed.
+        let arc = graph
+            .arcs()
+            .find(|arc| {
+                !is_quadrilateral(arc.into_source_vertex())
+                    && is_quadrilateral(arc.into_destination_vertex())
+            })
+            .unwrap();
+        assert_eq!(8, arc.edge_ring().count());
+    }
+
+    #[test]
+    fn cut_loop() {
+        let mut graph: MeshGraph<Point3<f64>> =
+            Cube::new().polygons::<Position<Point3<f64>>>().collect();
+        let key = graph.arcs().nth(0).unwrap().key();
+        let (a, b) = {
+            let arc = graph.arc(key).unwrap();
+            (
+                *arc.source_vertex().position(),
+                *arc.destination_vertex().position(),
+            )
+        };
+        let path = graph.cut_loop(key, 0.25).unwrap();
+        let origin = *path.back().position();
+
+        // The loop wraps around the cube in a plane orthogonal to the arc.
+        assert!(path.is_closed());
+        assert_eq!(4, path.arcs().count());
+        assert!((origin - (a + ((b - a) * 0.25))).norm() < 1e-9);
+        assert!(path
+            .vertices()
+            .all(|vertex| (*vertex.position() - origin).dot(&(b - a)).abs() < 1e-9));
+        assert_eq!(12, graph.vertex_count());
+        assert_eq!(20, graph.edge_count());
+        assert_eq!(10, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+    }
+
+    #[test]
+    fn cut_loop_pole() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(8, 4).polygons::<Position<E3>>().collect();
+        let (vertices, faces) = (graph.vertex_count(), graph.face_count());
+        let key = graph
+            .arcs()
+            .find(|arc| {
+                arc.adjacent_vertices()
+                    .all(|vertex| vertex.adjacent_faces().all(|face| face.arity() == 4))
+            })
+            .unwrap()
+            .key();
+        let path = graph.cut_loop(key, 0.5).unwrap();
+
+        // The ring stops at the triangles about the poles, which are not
+        // split but gain a vertex.
+        assert!(path.is_open());
+        assert_eq!(2, path.arcs().count());
+        assert_eq!(vertices + 3, graph.vertex_count());
+        assert_eq!(faces + 2, graph.face_count());
+        assert_eq!(14, graph.faces().filter(|face| face.arity() == 3).count());
+    }
+
+    #[test]
+    fn cut_loop_geometry_error() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.arcs().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::Geometry,
+            graph.cut_loop(key, 1.0).err().unwrap()
+        );
+        assert_eq!(8, graph.vertex_count());
+    }
+
+    #[test]
+    fn flip_edge() {
+        // Construct a quadrilateral from two triangles.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 0, 2, 3],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+            3,
+        )
+        .unwrap();
+
+        let ab = find_arc(&graph, ((0.0, 0.0), (1.0, 1.0))).unwrap();
+        let arc = graph.arc_mut(ab).unwrap().flip().unwrap().into_ref();
+
+        assert_eq!(
+            E2::from_geometry((1.0, 0.0)),
+            *arc.source_vertex().position()
+        );
+        assert_eq!(
+            E2::from_geometry((0.0, 1.0)),
+            *arc.destination_vertex().position()
+        );
+        assert_eq!(2, arc.adjacent_faces().count());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        assert!(find_arc(&graph, ((0.0, 0.0), (1.0, 1.0))).is_none());
+    }
+
+    #[test]
+    fn flip_edge_arity_error() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let ab = graph.arcs().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::ArityConflict {
+                expected: 3,
+                actual: 4
+            },
+            graph.arc_mut(ab).unwrap().flip().err().unwrap()
+        );
+    }
+
+    #[test]
+    fn flip_edge_topology_error() {
+        // Construct a tetrahedron. The vertices opposite any edge are already
+        // joined by an edge.
+        let mut graph = MeshGraph::<E3>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 0, 3, 1, 1, 3, 2, 0, 2, 3],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (0.0, 0.0, 1.0),
+            ],
+            3,
+        )
+        .unwrap();
+        let ab = graph.arcs().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph.arc_mut(ab).unwrap().flip().err().unwrap()
+        );
+        assert_eq!(6, graph.edge_count());
+    }
+
     #[test]
     fn remove_edge() {
         // Construct a graph with two connected quadrilaterals.
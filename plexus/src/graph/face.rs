@@ -1,30 +1,34 @@
 use derivative::Derivative;
 use fool::BoolExt;
+use num::NumCast;
 use slotmap::DefaultKey;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::cmp;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::mem;
 use std::ops::{Deref, DerefMut};
+use theon::ops::Cross;
 use theon::query::{Intersection, Line, Plane};
-use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
-use typenum::U3;
+use typenum::{NonZero, Unsigned, U3};
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
 use crate::entity::storage::{AsStorage, AsStorageMut, OpaqueKey, SlotStorage};
 use crate::entity::traverse::{Adjacency, Breadth, Depth, Trace, TraceFirst, Traversal};
 use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::Entity;
+use crate::geometry::triangulation::{self, Planar};
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
-use crate::graph::geometry::{FaceCentroid, FaceNormal, FacePlane, VertexPosition};
+use crate::graph::geometry::{into_f64, FaceCentroid, FaceNormal, FacePlane, VertexPosition};
 use crate::graph::mutation::face::{
     self, FaceBridgeCache, FaceExtrudeCache, FaceInsertCache, FacePokeCache, FaceRemoveCache,
-    FaceSplitCache,
+    FaceSplitCache, FaceTriangulateCache,
 };
+use crate::graph::mutation::path::{self, PathExtrudeSurfaceCache};
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
@@ -258,6 +262,33 @@ where
     {
         G::plane(self.to_ref())
     }
+
+    // Computes the triangulation of the face by ear clipping. Diagonals that
+    // would join vertices that are already joined by an arc outside of the face
+    // or for which the given function returns `false` are rejected.
+    pub(in crate::graph) fn ear_clip_cache<N, F>(
+        &self,
+        mut f: F,
+    ) -> Result<FaceTriangulateCache, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: Planar<N> + FiniteDimensional<N = N>,
+        N: NonZero + Unsigned,
+        F: FnMut(ArcKey) -> bool,
+    {
+        let perimeter = self
+            .adjacent_vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+        let points = Planar::project_planar(perimeter.iter().map(|(_, position)| *position))
+            .ok_or_else(|| GraphError::Geometry)?;
+        let triangles = triangulation::ear_clip_with(&points, |i, j| {
+            let ab = (perimeter[i].0, perimeter[j].0).into();
+            Rebind::<_, ArcView<_>>::rebind(self.to_ref(), ab).is_none() && f(ab)
+        })
+        .ok_or_else(|| GraphError::Geometry)?;
+        FaceTriangulateCache::from_face(self.to_ref(), triangles)
+    }
 }
 
 impl<B, M, G> FaceView<B>
@@ -654,6 +685,67 @@ where
         face
     }
 
+    /// Decomposes the face into triangles by ear clipping. Does nothing if
+    /// the face is triangular.
+    ///
+    /// Unlike [`triangulate`], ear clipping considers the positions of the
+    /// vertices in the face. The face is projected into its plane and
+    /// decomposed such that triangles have the same winding as the face, even
+    /// if the face is concave or nearly degenerate. The data of the face is
+    /// copied into each triangle.
+    ///
+    /// Returns the terminating face of the decomposition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the face is degenerate or self-intersecting, if the
+    /// positions of its vertices cannot be projected into a plane, or if the
+    /// face cannot be decomposed without inserting an edge that already exists.
+    ///
+    /// # Examples
+    ///
+    /// Triangulating a concave quadrilateral:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 2, 3)],
+    ///     vec![(0.0, 0.0), (3.0, 1.0), (0.0, 2.0), (1.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph.face_mut(key).unwrap().ear_clip().unwrap();
+    ///
+    /// assert_eq!(2, graph.face_count());
+    /// ```
+    ///
+    /// [`triangulate`]: crate::graph::FaceView::triangulate
+    pub fn ear_clip<N>(self) -> Result<Self, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: Planar<N> + FiniteDimensional<N = N>,
+        N: NonZero + Unsigned,
+    {
+        if self.arity() == 3 {
+            return Ok(self);
+        }
+        let cache = self.ear_clip_cache(|_| true)?;
+        let (storage, _) = self.unbind();
+        Ok(Mutation::replace(storage, Default::default())
+            .commit_with(|mutation| face::triangulate(mutation, cache))
+            .map(|(storage, keys)| {
+                Bind::bind(storage, keys.last().cloned().expect_consistent()).expect_consistent()
+            })
+            .expect_consistent())
+    }
+
     /// Subdivides the face about a vertex. A triangle fan is formed from each
     /// arc in the face's perimeter and the vertex.
     ///
@@ -819,6 +911,94 @@ where
             .expect_consistent()
     }
 
+    /// Insets the face.
+    ///
+    /// Insetting copies the vertices of the face, moves the face to these
+    /// copies, and inserts a ring of quadrilaterals between the face and its
+    /// original perimeter. The edges of the inset face are offset by the given
+    /// inset distance into the plane of the face and the inset face is
+    /// translated along its normal by the given depth. Unlike subdividing and
+    /// extruding a face, insetting retains the shape of non-convex faces.
+    ///
+    /// Returns the inset face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the geometry could not be computed, such as when
+    /// the face is degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+    /// let face_count = graph.face_count();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// let arity = graph
+    ///     .face_mut(key)
+    ///     .unwrap()
+    ///     .inset_with(0.1, 0.0)
+    ///     .unwrap()
+    ///     .arity();
+    ///
+    /// assert_eq!(face_count + arity, graph.face_count());
+    /// ```
+    pub fn inset_with<T>(self, inset: T, depth: T) -> Result<FaceView<&'a mut M>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let (storage, abc) = self.unbind();
+        let (storage, keys) = inset_region(
+            storage,
+            Some(abc).into_iter().collect(),
+            inset.into(),
+            depth.into(),
+        )?;
+        Ok(ArcView::bind(storage, (keys[0], keys[1]).into())
+            .and_then(|arc| arc.into_face())
+            .expect_consistent())
+    }
+
+    /// Bevels the face.
+    ///
+    /// Beveling insets the face by the given offset and translates the inset
+    /// face along its normal by the same offset, forming a ring of faces that
+    /// slant toward the inset face. See [`FaceView::inset_with`].
+    ///
+    /// Returns the beveled face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the geometry could not be computed, such as when
+    /// the face is degenerate.
+    ///
+    /// [`FaceView::inset_with`]: crate::graph::FaceView::inset_with
+    pub fn bevel<T>(self, offset: T) -> Result<FaceView<&'a mut M>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let offset = offset.into();
+        self.inset_with(offset, offset)
+    }
+
     /// Removes the face.
     ///
     /// Returns the remaining ring of the face if it is not entirely disjoint, otherwise `None`.
@@ -1712,12 +1892,195 @@ where
     }
 }
 
+// Insets a region of faces bounded by a single closed path. This is exposed
+// via `FaceView::inset_with` and `MeshGraph::inset_faces_with`.
+//
+// Returns the storage and the keys of the vertices along the path that bounds
+// the inset region.
+pub(in crate::graph) fn inset_region<'a, M, G>(
+    storage: &'a mut M,
+    faces: HashSet<FaceKey>,
+    inset: Scalar<VertexPosition<G>>,
+    depth: Scalar<VertexPosition<G>>,
+) -> Result<(&'a mut M, Vec<VertexKey>), GraphError>
+where
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Default
+        + Mutable<Data = G>,
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+{
+    let (cache, data) = {
+        let storage = &*storage;
+        let ring = region_ring(storage, &faces)?;
+        let path = Path::bind(storage, ring.iter().chain(ring.first()))?;
+        let cache = PathExtrudeSurfaceCache::from_path(path)?;
+        let data = inset_data(storage, &ring, &faces, inset, depth)?;
+        (cache, data)
+    };
+    Ok(Mutation::replace(storage, Default::default())
+        .commit_with(|mutation| {
+            path::extrude_surface_with(mutation, cache, |a, geometry| {
+                data.get(&a).cloned().unwrap_or(geometry)
+            })
+        })
+        .expect_consistent())
+}
+
+// Gets the vertices along the boundary of a region of faces in order. The
+// region is to the left of the boundary.
+fn region_ring<M, G>(storage: &M, faces: &HashSet<FaceKey>) -> Result<Vec<VertexKey>, GraphError>
+where
+    M: AsStorage<Arc<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    let mut boundary = HashMap::new();
+    for abc in faces.iter().cloned() {
+        let face = FaceView::bind(storage, abc).ok_or_else(|| GraphError::TopologyNotFound)?;
+        for arc in face.into_adjacent_arcs() {
+            let is_boundary = arc
+                .opposite_arc()
+                .face()
+                .map_or(true, |face| !faces.contains(&face.key()));
+            if is_boundary {
+                let (a, b) = arc.key().into();
+                // Regions that meet at a single vertex are not bounded by a
+                // single path.
+                if boundary.insert(a, b).is_some() {
+                    return Err(GraphError::TopologyConflict);
+                }
+            }
+        }
+    }
+    let mut ring = Vec::with_capacity(boundary.len());
+    let mut a = *boundary
+        .keys()
+        .next()
+        .ok_or_else(|| GraphError::TopologyMalformed)?;
+    while ring.len() < boundary.len() {
+        ring.push(a);
+        a = boundary[&a];
+        if a == ring[0] {
+            break;
+        }
+    }
+    // Regions with holes are not bounded by a single path.
+    if ring.len() != boundary.len() {
+        return Err(GraphError::TopologyConflict);
+    }
+    Ok(ring)
+}
+
+// Computes the data of the vertices in an inset region. Vertices along the
+// boundary of the region are offset into the region and all vertices are
+// translated along the normal of the region at each vertex.
+fn inset_data<M, G>(
+    storage: &M,
+    ring: &[VertexKey],
+    faces: &HashSet<FaceKey>,
+    inset: Scalar<VertexPosition<G>>,
+    depth: Scalar<VertexPosition<G>>,
+) -> Result<HashMap<VertexKey, G::Vertex>, GraphError>
+where
+    M: AsStorage<Arc<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+{
+    let mut normals = HashMap::<_, Vector<VertexPosition<G>>>::new();
+    for abc in faces.iter().cloned() {
+        let face = FaceView::bind(storage, abc).ok_or_else(|| GraphError::TopologyNotFound)?;
+        // Unlike `FaceView::normal`, this normal is computed from the area of
+        // the face and is consistent for non-convex faces.
+        let positions = face
+            .adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<SmallVec<[_; 4]>>();
+        let origin = positions[0];
+        let normal = positions[1..]
+            .windows(2)
+            .map(|ab| (ab[0] - origin).cross(ab[1] - origin))
+            .fold(None, |sum: Option<Vector<VertexPosition<G>>>, normal| {
+                Some(sum.map_or(normal, |sum| sum + normal))
+            })
+            .and_then(|normal| normal.normalize())
+            .ok_or_else(|| GraphError::Geometry)?;
+        for a in face.adjacent_vertices().keys() {
+            normals
+                .entry(a)
+                .and_modify(|sum| *sum = *sum + normal)
+                .or_insert(normal);
+        }
+    }
+    let position = |a: VertexKey| {
+        VertexView::bind(storage, a)
+            .map(|vertex| *vertex.position())
+            .ok_or_else(|| GraphError::TopologyNotFound)
+    };
+    let mut data = HashMap::with_capacity(normals.len());
+    for (a, normal) in normals.iter() {
+        let normal = normal.normalize().ok_or_else(|| GraphError::Geometry)?;
+        let geometry = VertexView::bind(storage, *a)
+            .ok_or_else(|| GraphError::TopologyNotFound)?
+            .data;
+        let translation = normal * depth;
+        data.insert(
+            *a,
+            geometry.map_position(|position| *position + translation),
+        );
+    }
+    // Offset the vertices along the boundary such that each inset edge is
+    // parallel to its original edge at a distance given by the inset.
+    for (n, b) in ring.iter().cloned().enumerate() {
+        let a = ring[(n + ring.len() - 1) % ring.len()];
+        let c = ring[(n + 1) % ring.len()];
+        let normal = normals[&b]
+            .normalize()
+            .ok_or_else(|| GraphError::Geometry)?;
+        let inward =
+            |from: VertexKey, to: VertexKey| -> Result<Vector<VertexPosition<G>>, GraphError> {
+                let edge = position(to)? - position(from)?;
+                normal
+                    .cross(edge)
+                    .normalize()
+                    .ok_or_else(|| GraphError::Geometry)
+            };
+        let (ab, bc) = (inward(a, b)?, inward(b, c)?);
+        // The magnitude of the offset increases as the angle between edges
+        // decreases. Edges that fold back onto each other cannot be offset.
+        let k = 1.0 + into_f64(ab.dot(bc))?;
+        if k <= std::f64::EPSILON {
+            return Err(GraphError::Geometry);
+        }
+        let scale = <Scalar<VertexPosition<G>> as NumCast>::from(1.0 / k)
+            .ok_or_else(|| GraphError::Geometry)?;
+        let translation = (ab + bc) * (inset * scale);
+        let origin = *data[&b].as_position();
+        *data.get_mut(&b).expect_consistent().as_position_mut() = origin + translation;
+    }
+    Ok(data)
+}
+
 #[cfg(test)]
 mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
 
-    use crate::graph::MeshGraph;
+    use crate::graph::{GraphError, MeshGraph};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
@@ -1894,6 +2257,212 @@ mod tests {
         assert_eq!(12, graph.face_count());
     }
 
+    #[test]
+    fn ear_clip_concave_face() {
+        // Construct an L-shaped hexagon with a reflex vertex at (1, 1).
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3, 4, 5],
+            vec![
+                (0.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 1.0),
+                (1.0, 1.0),
+                (1.0, 2.0),
+                (0.0, 2.0),
+            ],
+            6,
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().ear_clip().unwrap();
+
+        assert_eq!(4, graph.face_count());
+        assert_eq!(9, graph.edge_count());
+        // All triangles are wound counter-clockwise.
+        for face in graph.faces() {
+            let positions = face
+                .adjacent_vertices()
+                .map(|vertex| *vertex.position())
+                .collect::<Vec<_>>();
+            let (a, b, c) = (positions[0], positions[1], positions[2]);
+            let area = ((b.x - a.x) * (c.y - a.y)) - ((b.y - a.y) * (c.x - a.x));
+            assert!(area > R64::from(0.0));
+        }
+    }
+
+    #[test]
+    fn ear_clip_mesh() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(8, 8)
+            .polygons::<Position<E3>>() // 48 quadrilaterals, 16 triangles.
+            .collect();
+        graph.ear_clip().unwrap();
+
+        assert_eq!(112, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+    }
+
+    #[test]
+    fn ear_clip_degenerate_face_error() {
+        // Construct a self-intersecting quadrilateral.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3],
+            vec![(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0)],
+            4,
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::Geometry,
+            graph.face_mut(key).unwrap().ear_clip().err().unwrap()
+        );
+        assert_eq!(1, graph.face_count());
+    }
+
+    #[test]
+    fn ear_clip_mesh_error() {
+        // Construct a convex quadrilateral and a disjoint self-intersecting
+        // quadrilateral.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3, 4, 5, 6, 7],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (0.0, 1.0),
+                (2.0, 0.0),
+                (3.0, 1.0),
+                (3.0, 0.0),
+                (2.0, 1.0),
+            ],
+            4,
+        )
+        .unwrap();
+
+        assert_eq!(GraphError::Geometry, graph.ear_clip().err().unwrap());
+        // No faces are triangulated.
+        assert_eq!(2, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+    }
+
+    #[test]
+    fn inset_concave_face() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3, 4, 5],
+            vec![
+                (0.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (2.0, 1.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (1.0, 2.0, 0.0),
+                (0.0, 2.0, 0.0),
+            ],
+            6,
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let face = graph.face_mut(key).unwrap().inset_with(0.25, 0.5).unwrap();
+        let positions = face
+            .adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+
+        assert_eq!(6, positions.len());
+        // The reflex vertex must remain reflex.
+        for position in &[
+            Point3::new(0.25, 0.25, 0.5),
+            Point3::new(1.75, 0.25, 0.5),
+            Point3::new(0.75, 0.75, 0.5),
+        ] {
+            assert!(positions
+                .iter()
+                .any(|inset| (inset - position).norm() < 1e-9));
+        }
+        assert_eq!(7, graph.face_count());
+        assert_eq!(12, graph.vertex_count());
+    }
+
+    #[test]
+    fn inset_faces() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 4, 3), Tetragon::new(1, 2, 5, 4)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (2.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let keys = graph.faces().keys().collect::<Vec<_>>();
+        let path = graph.inset_faces_with(keys, 0.25, 0.0).unwrap();
+
+        assert_eq!(6, path.arcs().count());
+        assert!(path
+            .vertices()
+            .any(|vertex| (vertex.position() - Point3::new(1.0, 0.25, 0.0)).norm() < 1e-9));
+        assert_eq!(8, graph.face_count());
+        assert_eq!(12, graph.vertex_count());
+    }
+
+    #[test]
+    fn inset_disjoint_faces_error() {
+        // Create a grid of four quadrilaterals.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![
+                Tetragon::new(0usize, 1, 4, 3),
+                Tetragon::new(1, 2, 5, 4),
+                Tetragon::new(3, 4, 7, 6),
+                Tetragon::new(4, 5, 8, 7),
+            ],
+            (0..3)
+                .flat_map(|y| (0..3).map(move |x| (f64::from(x), f64::from(y), 0.0)))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        // Select diagonal faces, which only share a vertex.
+        let keys = graph
+            .faces()
+            .filter(|face| {
+                let centroid = face.centroid();
+                (centroid.x - centroid.y).abs() < 1e-9
+            })
+            .keys()
+            .collect::<Vec<_>>();
+
+        assert_eq!(2, keys.len());
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph.inset_faces_with(keys, 0.25, 0.0).err().unwrap()
+        );
+        assert_eq!(4, graph.face_count());
+    }
+
+    #[test]
+    fn bevel_face() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+            4,
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let face = graph.face_mut(key).unwrap().bevel(0.25).unwrap();
+
+        assert!((face.centroid() - Point3::new(0.5, 0.5, 0.25)).norm() < 1e-9);
+        assert!(face
+            .adjacent_vertices()
+            .all(|vertex| (vertex.position().x - 0.5).abs() < 0.25 + 1e-9));
+        assert_eq!(5, graph.face_count());
+    }
+
     #[test]
     fn logical_metrics() {
         let graph = MeshGraph::<Point2<f32>>::from_raw_buffers_with_arity(
@@ -7,6 +7,7 @@
 // necessary, constraints are specified there so that they do not pollute user
 // code.
 
+use num::NumCast;
 use theon::adjunct::FromItems;
 use theon::ops::{Cross, Interpolate, Project};
 use theon::query::Plane;
@@ -296,3 +297,12 @@ where
 //        }
 //    }
 //}
+
+// Converts a scalar into an `f64`. Returns an error if the scalar cannot be
+// represented.
+pub(in crate::graph) fn into_f64<T>(value: T) -> Result<f64, GraphError>
+where
+    T: NumCast,
+{
+    <f64 as NumCast>::from(value).ok_or_else(|| GraphError::Geometry)
+}
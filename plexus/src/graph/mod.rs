@@ -253,6 +253,10 @@ mod face;
 mod geometry;
 mod mutation;
 mod path;
+mod serialize;
+mod simplify;
+mod smooth;
+mod subdivide;
 mod vertex;
 
 use decorum::cmp::IntrinsicOrd;
@@ -268,18 +272,22 @@ use std::hash::Hash;
 use std::iter::FromIterator;
 use std::vec;
 use theon::adjunct::{FromItems, Map};
+use theon::ops::Cross;
 use theon::query::Aabb;
-use theon::space::{EuclideanSpace, Scalar};
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 use thiserror::Error;
-use typenum::{self, NonZero};
+use typenum::{self, NonZero, U3};
 
 use crate::buffer::{BufferError, FromRawBuffers, FromRawBuffersWithArity, MeshBuffer};
 use crate::builder::{Buildable, FacetBuilder, MeshBuilder, SurfaceBuilder};
-use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::encoding::{
+    ArcDecoder, EdgeDecoder, FaceDecoder, FromEdgeEncoding, FromEncoding, VertexDecoder,
+};
 use crate::entity::storage::{AsStorage, AsStorageMut, AsStorageOf, Fuse, OpaqueKey, Storage};
 use crate::entity::view::{Bind, Orphan, View};
 use crate::entity::EntityError;
+use crate::geometry::triangulation::Planar;
 use crate::geometry::{FromGeometry, IntoGeometry};
 use crate::graph::builder::GraphBuilder;
 use crate::graph::core::{Core, OwnedCore};
@@ -289,7 +297,7 @@ use crate::graph::mutation::{Consistent, Mutation};
 use crate::index::{Flat, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexVertices, Indexer};
 use crate::primitive::decompose::IntoVertices;
 use crate::primitive::{IntoPolygons, Polygonal, UnboundedPolygon};
-use crate::transact::Transact;
+use crate::transact::{Mutate, Transact};
 use crate::{DynamicArity, MeshArity, StaticArity};
 
 pub use crate::entity::view::{ClosedView, Rebind};
@@ -303,6 +311,9 @@ pub use crate::graph::geometry::{
     VertexPosition,
 };
 pub use crate::graph::path::Path;
+pub use crate::graph::simplify::{Placement, Simplification};
+pub use crate::graph::smooth::{Smoothing, Weighting};
+pub use crate::graph::subdivide::WeightedMean;
 pub use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
 
 pub use Selector::ByIndex;
@@ -481,6 +492,53 @@ impl<K> From<usize> for Selector<K> {
     }
 }
 
+/// Mapping of keys from a [`MeshGraph`] into a graph derived from it.
+///
+/// A rekeying maps the keys of entities in a source graph to the keys of the
+/// same entities in a destination graph. See
+/// [`MeshGraph::into_disjoint_subgraphs_with_rekeying`] and, with the `serde`
+/// feature, `MeshGraph::deserialize_with_rekeying`.
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::into_disjoint_subgraphs_with_rekeying`]: crate::graph::MeshGraph::into_disjoint_subgraphs_with_rekeying
+#[derive(Clone, Debug, Default)]
+pub struct SubgraphRekeying {
+    vertices: HashMap<VertexKey, VertexKey>,
+    arcs: HashMap<ArcKey, ArcKey>,
+    edges: HashMap<EdgeKey, EdgeKey>,
+    faces: HashMap<FaceKey, FaceKey>,
+}
+
+impl SubgraphRekeying {
+    /// Gets the destination key of a vertex.
+    ///
+    /// Returns `None` if the vertex is not in the destination graph.
+    pub fn vertex(&self, key: VertexKey) -> Option<VertexKey> {
+        self.vertices.get(&key).cloned()
+    }
+
+    /// Gets the destination key of an arc.
+    ///
+    /// Returns `None` if the arc is not in the destination graph.
+    pub fn arc(&self, key: ArcKey) -> Option<ArcKey> {
+        self.arcs.get(&key).cloned()
+    }
+
+    /// Gets the destination key of an edge.
+    ///
+    /// Returns `None` if the edge is not in the destination graph.
+    pub fn edge(&self, key: EdgeKey) -> Option<EdgeKey> {
+        self.edges.get(&key).cloned()
+    }
+
+    /// Gets the destination key of a face.
+    ///
+    /// Returns `None` if the face is not in the destination graph.
+    pub fn face(&self, key: FaceKey) -> Option<FaceKey> {
+        self.faces.get(&key).cloned()
+    }
+}
+
 /// [Half-edge graph][dcel] representation of a polygonal mesh.
 ///
 /// `MeshGraph`s form a polygonal mesh from four interconnected entities:
@@ -696,10 +754,9 @@ where
 
     // TODO: This triangulation does not consider geometry and exhibits some
     //       bad behavior in certain situations. Triangulation needs to be
-    //       reworked and may need to expose a bit more complexity. A geometric
-    //       triangulation algorithm would be a useful addition and could
-    //       detect concave faces and provide more optimal splits. See comments
-    //       on `FaceView::triangulate`.
+    //       reworked and may need to expose a bit more complexity. See
+    //       `ear_clip` for a geometric triangulation that supports concave
+    //       faces and comments on `FaceView::triangulate`.
     /// Triangulates the graph, tessellating all faces into triangles.
     pub fn triangulate(&mut self) {
         // TODO: This implementation is a bit fragile and depends on the
@@ -734,6 +791,49 @@ where
         }
     }
 
+    /// Triangulates the graph by ear clipping, tessellating all faces into
+    /// triangles.
+    ///
+    /// Unlike [`triangulate`], ear clipping considers the positions of
+    /// vertices and supports concave and nearly degenerate faces. See
+    /// [`FaceView::ear_clip`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any face cannot be triangulated. All faces are
+    /// triangulated before the graph is modified, so the graph is not modified
+    /// if an error occurs.
+    ///
+    /// [`triangulate`]: crate::graph::MeshGraph::triangulate
+    /// [`FaceView::ear_clip`]: crate::graph::FaceView::ear_clip
+    pub fn ear_clip<N>(&mut self) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: Planar<N> + FiniteDimensional<N = N>,
+        N: NonZero + typenum::Unsigned,
+    {
+        // All faces are triangulated within a single mutation, so diagonals
+        // that have been chosen for a face are rejected for any other faces as
+        // if they had already been inserted.
+        let mut arcs = HashSet::new();
+        let mut caches = Vec::new();
+        for face in self.faces().filter(|face| face.arity() > 3) {
+            let cache = face.ear_clip_cache(|ab| !arcs.contains(&ab))?;
+            arcs.extend(cache.arcs());
+            caches.push(cache);
+        }
+        Mutation::replace(self, Default::default())
+            .commit_with(|mutation| -> Result<_, GraphError> {
+                let mutation: &mut Mutation<_> = mutation.as_mut();
+                for cache in caches {
+                    mutation::face::triangulate(&mut *mutation, cache)?;
+                }
+                Ok(())
+            })
+            .expect_consistent();
+        Ok(())
+    }
+
     /// Smooths the positions of vertices in the graph.
     ///
     /// Each position is translated by its offset from its centroid scaled by
@@ -741,6 +841,11 @@ where
     /// positions of its adjacent vertices. That is, given a factor $k$ and a
     /// vertex with position $P$ and centroid $Q$, its position becomes
     /// $P+k(Q-P)$.
+    ///
+    /// This shrinks the graph when applied repeatedly. See
+    /// [`MeshGraph::smooth_with`] for other smoothing modes.
+    ///
+    /// [`MeshGraph::smooth_with`]: crate::graph::MeshGraph::smooth_with
     pub fn smooth<T>(&mut self, factor: T)
     where
         T: Into<Scalar<VertexPosition<G>>>,
@@ -762,17 +867,428 @@ where
         }
     }
 
+    /// Smooths the positions of vertices in the graph using the given
+    /// [`Smoothing`].
+    ///
+    /// Only vertices for which the given function returns `true` are moved.
+    /// Vertices are moved toward a weighted mean of the positions of their
+    /// adjacent vertices using either uniform or cotangent weights. Taubin
+    /// smoothing can be used to smooth noise without significantly shrinking
+    /// the graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cotangent weights are used and the graph is not a
+    /// triangle mesh or if a factor or weight cannot be converted to or from
+    /// the scalar type of positions.
+    ///
+    /// # Examples
+    ///
+    /// Applying Taubin smoothing to a sphere:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, Smoothing};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<E3>>()
+    ///     .collect();
+    /// graph
+    ///     .smooth_with(
+    ///         Smoothing::default()
+    ///             .with_factor(0.5)
+    ///             .with_taubin(-0.53)
+    ///             .with_iterations(10),
+    ///         |_| true,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`Smoothing`]: crate::graph::Smoothing
+    pub fn smooth_with<F>(&mut self, smoothing: Smoothing, f: F) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        F: FnMut(VertexView<&Self>) -> bool,
+    {
+        smooth::smooth(self, smoothing, f)
+    }
+
+    /// Simplifies the graph by collapsing edges.
+    ///
+    /// Edges are collapsed in order of increasing error using quadric error
+    /// metrics until the target number of faces or the maximum error given by
+    /// the [`Simplification`] is reached. Collapses that would break the
+    /// manifold topology of the graph, fold faces, or place vertices at
+    /// positions that cannot be represented are skipped. The data of arcs,
+    /// edges, and faces that remain in the graph is retained and the data of
+    /// vertices formed by collapses is copied from one of the vertices of the
+    /// collapsed edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not a triangle mesh or if positions
+    /// cannot be converted to `f64`, in which case the graph is not modified.
+    /// Collapses that cannot be applied are skipped, so no other errors are
+    /// expected. If an error occurs while collapsing edges, then it is
+    /// returned and the graph is left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, Simplification};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(32, 16)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// graph
+    ///     .simplify(Simplification::default().with_face_count(256))
+    ///     .unwrap();
+    ///
+    /// assert!(graph.face_count() <= 256);
+    /// ```
+    ///
+    /// [`Simplification`]: crate::graph::Simplification
+    pub fn simplify(&mut self, simplification: Simplification) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        simplify::simplify(self, simplification)
+    }
+
+    /// Subdivides the graph using Catmull-Clark subdivision.
+    ///
+    /// Each face is replaced by a quadrilateral for each vertex in its
+    /// perimeter and the data of vertices is computed as weighted means via
+    /// [`WeightedMean`]. Boundary edges are treated as sharp creases. The data
+    /// of arcs and edges is copied into the arcs and edges that split them and
+    /// the data of faces is copied into the faces that replace them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.subdivide_catmull_clark();
+    ///
+    /// assert_eq!(24, graph.face_count());
+    /// ```
+    ///
+    /// [`WeightedMean`]: crate::graph::WeightedMean
+    pub fn subdivide_catmull_clark(&mut self)
+    where
+        G::Vertex: WeightedMean,
+    {
+        self.subdivide_catmull_clark_with(|_| false)
+    }
+
+    /// Subdivides the graph using Catmull-Clark subdivision with sharp
+    /// creases.
+    ///
+    /// The given function is used to select sharp edges. Vertices along
+    /// sharp edges are only affected by adjacent vertices along those edges
+    /// and vertices at which three or more sharp edges meet are not moved.
+    /// Because edge data is copied into the edges that split them, creases
+    /// selected by edge data are preserved across repeated subdivision.
+    ///
+    /// See [`MeshGraph::subdivide_catmull_clark`].
+    ///
+    /// # Examples
+    ///
+    /// Keeping the corners of a cube sharp:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.subdivide_catmull_clark_with(|_| true);
+    /// ```
+    ///
+    /// [`MeshGraph::subdivide_catmull_clark`]: crate::graph::MeshGraph::subdivide_catmull_clark
+    pub fn subdivide_catmull_clark_with<F>(&mut self, f: F)
+    where
+        G::Vertex: WeightedMean,
+        F: FnMut(EdgeView<&Self>) -> bool,
+    {
+        subdivide::catmull_clark(self, f)
+    }
+
+    /// Subdivides a triangle graph using Loop subdivision.
+    ///
+    /// Each iteration replaces each triangle with four triangles and the data
+    /// of vertices is computed as weighted means via [`WeightedMean`].
+    /// Boundary edges are treated as sharp creases. Unlike
+    /// [`MeshGraph::smooth`], Loop subdivision approximates a smooth limit
+    /// surface and does not significantly shrink the graph. The data of arcs
+    /// and edges is copied into the arcs and edges that split them and the
+    /// data of faces is copied into the faces that replace them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not a triangle mesh. The graph is not
+    /// modified if an error occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = UvSphere::new(8, 8)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .collect();
+    /// let face_count = graph.face_count();
+    /// graph.subdivide_loop(2).unwrap();
+    ///
+    /// assert_eq!(face_count * 16, graph.face_count());
+    /// ```
+    ///
+    /// [`MeshGraph::smooth`]: crate::graph::MeshGraph::smooth
+    /// [`WeightedMean`]: crate::graph::WeightedMean
+    pub fn subdivide_loop(&mut self, iterations: usize) -> Result<(), GraphError>
+    where
+        G::Vertex: WeightedMean,
+    {
+        subdivide::loop_subdivision(self, iterations)
+    }
+
+    /// Insets a region of faces.
+    ///
+    /// The faces must form a connected region that is bounded by a single
+    /// closed path. The vertices along this path are copied and the region is
+    /// moved to these copies, with a ring of quadrilaterals inserted between
+    /// the region and its original boundary. The boundary of the region is
+    /// offset inward by the given inset distance and all vertices in the region
+    /// are translated along the normal of the region by the given depth.
+    ///
+    /// Returns the path along the boundary of the inset region.
+    ///
+    /// See [`FaceView::inset_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the faces are not found, if the faces do not form a
+    /// region bounded by a single closed path, or if the geometry could not be
+    /// computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// // Create a graph from two adjacent quadrilaterals.
+    /// let mut graph = MeshGraph::<E3>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 4, 3), Tetragon::new(1, 2, 5, 4)],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (2.0, 0.0, 0.0),
+    ///         (0.0, 1.0, 0.0),
+    ///         (1.0, 1.0, 0.0),
+    ///         (2.0, 1.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let keys = graph.faces().keys().collect::<Vec<_>>();
+    /// let path = graph.inset_faces_with(keys, 0.25, 0.5).unwrap();
+    ///
+    /// assert_eq!(6, path.arcs().count());
+    /// assert_eq!(8, graph.face_count());
+    /// ```
+    ///
+    /// [`FaceView::inset_with`]: crate::graph::FaceView::inset_with
+    pub fn inset_faces_with<I, T>(
+        &mut self,
+        faces: I,
+        inset: T,
+        depth: T,
+    ) -> Result<Path<&mut Self>, GraphError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<FaceKey>,
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let faces = faces.into_iter().map(|key| *key.borrow()).collect();
+        let (storage, keys) = face::inset_region(self, faces, inset.into(), depth.into())?;
+        Ok(Path::bind(storage, keys).expect_consistent())
+    }
+
+    /// Bevels a region of faces.
+    ///
+    /// Beveling insets the region by the given offset and translates the inset
+    /// region along its normal by the same offset. See
+    /// [`MeshGraph::inset_faces_with`].
+    ///
+    /// Returns the path along the boundary of the beveled region.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the faces are not found, if the faces do not form a
+    /// region bounded by a single closed path, or if the geometry could not be
+    /// computed.
+    ///
+    /// [`MeshGraph::inset_faces_with`]: crate::graph::MeshGraph::inset_faces_with
+    pub fn bevel_faces<I, T>(&mut self, faces: I, offset: T) -> Result<Path<&mut Self>, GraphError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<FaceKey>,
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let offset = offset.into();
+        self.inset_faces_with(faces, offset, offset)
+    }
+
+    /// Cuts a loop across the edge ring of an arc.
+    ///
+    /// Every edge in the edge ring of the arc is split at the parametric
+    /// position $t$ and each quadrilateral in the ring is split between the
+    /// inserted vertices. For an arc $\overrightarrow{AB}$, the vertex in its
+    /// edge is placed at $A + t(B - A)$ and the vertices in the other edges of
+    /// the ring are placed from the same side of the ring. Triangles and other
+    /// polygons at the ends of an open ring, such as those at poles, are not
+    /// split and instead gain the inserted vertex along their perimeter. See
+    /// [`ArcView::into_edge_ring`].
+    ///
+    /// Returns the path of the inserted edge loop. The path is closed if the
+    /// edge ring is closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arc is not found, if the edge ring of the arc
+    /// does not cross any quadrilaterals or crosses a quadrilateral more than
+    /// once, or if $t$ is not in the open interval $(0, 1)$.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.arcs().nth(0).unwrap().key();
+    /// let path = graph.cut_loop(key, 0.5).unwrap();
+    ///
+    /// assert!(path.is_closed());
+    /// assert_eq!(4, path.arcs().count());
+    /// assert_eq!(10, graph.face_count());
+    /// ```
+    ///
+    /// [`ArcView::into_edge_ring`]: crate::graph::ArcView::into_edge_ring
+    pub fn cut_loop<T>(&mut self, ab: ArcKey, t: T) -> Result<Path<&mut Self>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let (storage, keys) = edge::cut_loop(self, ab, t.into())?;
+        Ok(Path::bind(storage, keys).expect_consistent())
+    }
+
     /// Splits the graph along a path.
     ///
     /// Splitting a graph creates boundaries along the given path and copies any
-    /// necessary vertex, arc, and edge geometry.
+    /// necessary vertex, arc, and edge geometry. Faces to the left of the path
+    /// (those adjacent to its arcs) remain adjacent to the original vertices
+    /// and faces to the right of the path are moved to copies. Vertices at the
+    /// ends of an open path are only copied if they are on a boundary.
     ///
     /// If the path bisects the graph, then splitting will result in disjointed
     /// sub-graphs.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if any arc in the path is a boundary arc or its
+    /// opposite arc is a boundary arc, because a graph is already split along
+    /// its boundaries. Returns an error if the path consists of a single arc
+    /// with no boundary vertices, because arcs cannot be duplicated between
+    /// the same vertices. Returns an error if the faces that are moved to
+    /// copied vertices cannot be inserted, in which case the graph is left
+    /// empty.
+    ///
     /// # Examples
     ///
-    /// ```rust,no_run
+    /// ```rust
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
@@ -802,12 +1318,14 @@ where
     ///     .find(|edge| !edge.is_boundary_edge())
     ///     .map(|edge| edge.into_arc().key())
     ///     .unwrap();
-    /// let mut path = graph.arc_mut(key).unwrap().into_path();
+    /// let path = graph.arc_mut(key).unwrap().into_path();
     /// MeshGraph::split_at_path(path).unwrap();
+    ///
+    /// assert_eq!(6, graph.vertex_count());
+    /// assert_eq!(2, graph.disjoint_subgraph_vertices().count());
     /// ```
     pub fn split_at_path(path: Path<&mut Self>) -> Result<(), GraphError> {
-        let _ = path;
-        unimplemented!()
+        path.split_graph()
     }
 
     /// Gets an iterator over a vertex within each disjoint sub-graph.
@@ -864,8 +1382,159 @@ where
     }
 
     /// Moves disjoint sub-graphs into separate graphs.
+    ///
+    /// All data is moved into the sub-graphs. See
+    /// [`disjoint_subgraph_vertices`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// // Create a graph from two disjoint triangles.
+    /// let graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(3, 4, 5)],
+    ///     vec![
+    ///         (-2.0, 0.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, 1.0),
+    ///         (1.0, 0.0),
+    ///         (2.0, 0.0),
+    ///         (1.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// for graph in graph.into_disjoint_subgraphs() {
+    ///     assert_eq!(1, graph.face_count());
+    /// }
+    /// ```
+    ///
+    /// [`disjoint_subgraph_vertices`]: crate::graph::MeshGraph::disjoint_subgraph_vertices
     pub fn into_disjoint_subgraphs(self) -> Vec<Self> {
-        unimplemented!()
+        self.into_disjoint_subgraphs_with_rekeying()
+            .into_iter()
+            .map(|(graph, _)| graph)
+            .collect()
+    }
+
+    /// Moves disjoint sub-graphs into separate graphs and maps keys into each
+    /// sub-graph.
+    ///
+    /// Each sub-graph is paired with a [`SubgraphRekeying`] that maps the keys
+    /// of its entities in the original graph to their keys in the sub-graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(3, 4, 5)],
+    ///     vec![
+    ///         (-2.0, 0.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, 1.0),
+    ///         (1.0, 0.0),
+    ///         (2.0, 0.0),
+    ///         (1.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    ///
+    /// // Find the sub-graph that contains the face.
+    /// let (graph, rekeying) = graph
+    ///     .into_disjoint_subgraphs_with_rekeying()
+    ///     .into_iter()
+    ///     .find(|(_, rekeying)| rekeying.face(key).is_some())
+    ///     .unwrap();
+    /// let face = graph.face(rekeying.face(key).unwrap()).unwrap();
+    /// ```
+    ///
+    /// [`SubgraphRekeying`]: crate::graph::SubgraphRekeying
+    pub fn into_disjoint_subgraphs_with_rekeying(self) -> Vec<(Self, SubgraphRekeying)> {
+        let mut subgraphs = HashMap::with_capacity(self.vertex_count());
+        for (n, vertex) in self.disjoint_subgraph_vertices().enumerate() {
+            for vertex in vertex.traverse_by_depth() {
+                subgraphs.insert(vertex.key(), n);
+            }
+        }
+        let n = subgraphs.values().max().map_or(0, |n| n + 1);
+        // Arcs and their edges and faces are in the same sub-graph as their
+        // source vertices.
+        let subgraph = |ab: ArcKey| {
+            let (a, _) = ab.into();
+            subgraphs[&a]
+        };
+        let (vertices, arcs, edges, faces) = self.core.unfuse();
+        let vertices = vertices.partition_rekey_by(n, |(a, _)| subgraphs[a]);
+        let edges = edges.partition_rekey_by(n, |(_, edge)| subgraph(edge.arc));
+        let faces = faces.partition_rekey_by(n, |(_, face)| subgraph(face.arc));
+        let mut partitions = (0..n)
+            .map(|_| (HashMap::new(), Storage::<Arc<G>>::new()))
+            .collect::<Vec<_>>();
+        for (ab, arc) in arcs.iter() {
+            let n = subgraph(ab);
+            let rekey = |ab: ArcKey| {
+                let (a, b) = ab.into();
+                ArcKey::from((vertices[n].0[&a], vertices[n].0[&b]))
+            };
+            let mut arc = *arc;
+            arc.next = arc.next.map(rekey);
+            arc.previous = arc.previous.map(rekey);
+            arc.edge = arc.edge.map(|key| edges[n].0[&key]);
+            arc.face = arc.face.map(|key| faces[n].0[&key]);
+            let (rekeying, storage) = &mut partitions[n];
+            rekeying.insert(ab, rekey(ab));
+            storage.insert_with_key(rekey(ab), arc);
+        }
+        let mut graphs = Vec::with_capacity(n);
+        for (((vertices, arcs), edges), faces) in
+            vertices.into_iter().zip(partitions).zip(edges).zip(faces)
+        {
+            let ((vertex_keys, mut vertices), (arc_keys, arcs)) = (vertices, arcs);
+            let ((edge_keys, mut edges), (face_keys, mut faces)) = (edges, faces);
+            for (_, vertex) in vertices.iter_mut() {
+                vertex.arc = vertex.arc.map(|ab| arc_keys[&ab]);
+            }
+            for (_, edge) in edges.iter_mut() {
+                edge.arc = arc_keys[&edge.arc];
+            }
+            for (_, face) in faces.iter_mut() {
+                face.arc = arc_keys[&face.arc];
+            }
+            let graph: Self = Core::empty()
+                .fuse(vertices)
+                .fuse(arcs)
+                .fuse(edges)
+                .fuse(faces)
+                .into();
+            let rekeying = SubgraphRekeying {
+                vertices: vertex_keys.into_iter().collect(),
+                arcs: arc_keys,
+                edges: edge_keys.into_iter().collect(),
+                faces: face_keys.into_iter().collect(),
+            };
+            graphs.push((graph, rekeying));
+        }
+        graphs
     }
 
     /// Creates a [`Buildable`] mesh data structure from the graph.
@@ -1235,21 +1904,45 @@ where
         vertices: <E as VertexDecoder>::Output,
         faces: <E as FaceDecoder>::Output,
     ) -> Result<Self, Self::Error> {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let keys = vertices
-            .into_iter()
-            .map(|geometry| mutation::vertex::insert(&mut mutation, geometry.into_geometry()))
-            .collect::<Vec<_>>();
-        for (perimeter, geometry) in faces {
-            let perimeter = perimeter
-                .into_iter()
-                .map(|index| keys[index])
-                .collect::<SmallVec<[_; 4]>>();
-            let cache = FaceInsertCache::from_storage(&mutation, perimeter.as_slice())?;
-            let geometry = geometry.into_geometry();
-            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), geometry))?;
+        decode::<E, G>(vertices, faces).map(|(graph, _)| graph)
+    }
+}
+
+impl<E, G> FromEdgeEncoding<E> for MeshGraph<G>
+where
+    E: ArcDecoder + EdgeDecoder + FaceDecoder + VertexDecoder,
+    G: GraphData,
+    G::Arc: FromGeometry<E::Arc>,
+    G::Edge: FromGeometry<E::Edge>,
+    G::Face: FromGeometry<E::Face>,
+    G::Vertex: FromGeometry<E::Vertex>,
+{
+    fn from_edge_encoding(
+        vertices: <E as VertexDecoder>::Output,
+        faces: <E as FaceDecoder>::Output,
+        arcs: <E as ArcDecoder>::Output,
+        edges: <E as EdgeDecoder>::Output,
+    ) -> Result<Self, Self::Error> {
+        let (mut graph, keys) = decode::<E, G>(vertices, faces)?;
+        let key = |(source, destination): (usize, usize)| -> Result<ArcKey, GraphError> {
+            let source = keys.get(source).ok_or(GraphError::TopologyNotFound)?;
+            let destination = keys.get(destination).ok_or(GraphError::TopologyNotFound)?;
+            Ok((*source, *destination).into())
+        };
+        for (indices, geometry) in arcs {
+            let mut arc = graph
+                .arc_mut(key(indices)?)
+                .ok_or(GraphError::TopologyNotFound)?;
+            arc.data = geometry.into_geometry();
         }
-        mutation.commit()
+        for (indices, geometry) in edges {
+            let mut edge = graph
+                .arc_mut(key(indices)?)
+                .ok_or(GraphError::TopologyNotFound)?
+                .into_edge();
+            edge.data = geometry.into_geometry();
+        }
+        Ok(graph)
     }
 }
 
@@ -1569,6 +2262,35 @@ where
     }
 }
 
+// Inserts decoded vertices and faces into a graph and returns the keys of its
+// vertices in the order in which they were decoded.
+fn decode<E, G>(
+    vertices: <E as VertexDecoder>::Output,
+    faces: <E as FaceDecoder>::Output,
+) -> Result<(MeshGraph<G>, Vec<VertexKey>), GraphError>
+where
+    E: FaceDecoder + VertexDecoder,
+    G: GraphData,
+    G::Face: FromGeometry<E::Face>,
+    G::Vertex: FromGeometry<E::Vertex>,
+{
+    let mut mutation = Mutation::from(MeshGraph::new());
+    let keys = vertices
+        .into_iter()
+        .map(|geometry| mutation::vertex::insert(&mut mutation, geometry.into_geometry()))
+        .collect::<Vec<_>>();
+    for (perimeter, geometry) in faces {
+        let perimeter = perimeter
+            .into_iter()
+            .map(|index| keys.get(index).cloned().ok_or(GraphError::TopologyNotFound))
+            .collect::<Result<SmallVec<[_; 4]>, _>>()?;
+        let cache = FaceInsertCache::from_storage(&mutation, perimeter.as_slice())?;
+        let geometry = geometry.into_geometry();
+        mutation::face::insert_with(&mut mutation, cache, || (Default::default(), geometry))?;
+    }
+    mutation.commit().map(|graph| (graph, keys))
+}
+
 #[cfg(test)]
 mod tests {
     use decorum::R64;
@@ -1576,8 +2298,10 @@ mod tests {
     use num::Zero;
 
     use crate::buffer::MeshBuffer3;
-    use crate::graph::{GraphData, GraphError, MeshGraph};
+    use crate::encoding::{ArcDecoder, EdgeDecoder, FaceDecoder, FromEdgeEncoding, VertexDecoder};
+    use crate::graph::{GraphData, GraphError, MeshGraph, Path};
     use crate::prelude::*;
+    use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
     use crate::primitive::NGon;
@@ -1646,6 +2370,93 @@ mod tests {
         assert_eq!(2, graph.disjoint_subgraph_vertices().count());
     }
 
+    #[test]
+    fn into_disjoint_subgraphs() {
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![
+                NGon([0u32, 1, 2, 3]),
+                NGon([4, 5, 6, 7]),
+                NGon([5, 8, 9, 6]),
+            ],
+            vec![
+                (-2.0, 0.0),
+                (-1.0, 0.0),
+                (-1.0, 1.0),
+                (-2.0, 1.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 1.0),
+                (1.0, 1.0),
+                (3.0, 0.0),
+                (3.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let keys = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+
+        let subgraphs = graph.into_disjoint_subgraphs_with_rekeying();
+        assert_eq!(2, subgraphs.len());
+        let mut counts = subgraphs
+            .iter()
+            .map(|(graph, _)| (graph.vertex_count(), graph.face_count()))
+            .collect::<Vec<_>>();
+        counts.sort();
+        assert_eq!(vec![(4, 1), (6, 2)], counts);
+        for (key, position) in keys {
+            // Each vertex is moved into exactly one sub-graph with its data.
+            let mut vertices = subgraphs.iter().flat_map(|(graph, rekeying)| {
+                rekeying
+                    .vertex(key)
+                    .map(|key| *graph.vertex(key).unwrap().position())
+            });
+            assert_eq!(Some(position), vertices.next());
+            assert_eq!(None, vertices.next());
+        }
+        for (graph, _) in subgraphs {
+            assert_eq!(1, graph.disjoint_subgraph_vertices().count());
+        }
+    }
+
+    #[test]
+    fn split_at_closed_path() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+
+        // Split the graph along the perimeter of a face.
+        let path: Path<_> = graph.face_mut(key).unwrap().into_ring().into();
+        MeshGraph::split_at_path(path).unwrap();
+
+        assert_eq!(12, graph.vertex_count());
+        assert_eq!(6, graph.face_count());
+        assert_eq!(2, graph.disjoint_subgraph_vertices().count());
+        assert_eq!(0, graph.face(key).unwrap().adjacent_faces().count());
+    }
+
+    #[test]
+    fn split_at_boundary_path_error() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove().unwrap();
+
+        // Split the graph along the boundary left by the removed face.
+        let key = graph
+            .arcs()
+            .find(|arc| arc.is_boundary_arc())
+            .map(|arc| arc.key())
+            .unwrap();
+        let path = graph.arc_mut(key).unwrap().into_path();
+
+        assert_eq!(
+            GraphError::TopologyConflict,
+            MeshGraph::split_at_path(path).err().unwrap()
+        );
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(5, graph.face_count());
+    }
+
     #[test]
     fn non_manifold_error_deferred() {
         let graph: MeshGraph<E3> = UvSphere::new(32, 32)
@@ -1696,4 +2507,88 @@ mod tests {
             assert_eq!(value, face.data);
         }
     }
+
+    #[test]
+    fn decode_edge_data() {
+        struct Crease;
+
+        impl GraphData for Crease {
+            type Vertex = Point3<f64>;
+            type Arc = bool;
+            type Edge = f64;
+            type Face = ();
+        }
+
+        // A decoder that describes a quadrilateral with a creased edge and a
+        // seam along one of its arcs.
+        struct Decoder;
+
+        impl VertexDecoder for Decoder {
+            type Output = Vec<Self::Vertex>;
+            type Vertex = Point3<f64>;
+        }
+
+        impl FaceDecoder for Decoder {
+            type Output = Vec<(Self::Index, Self::Face)>;
+            type Index = Vec<usize>;
+            type Face = ();
+        }
+
+        impl ArcDecoder for Decoder {
+            type Output = Vec<((usize, usize), Self::Arc)>;
+            type Arc = bool;
+        }
+
+        impl EdgeDecoder for Decoder {
+            type Output = Vec<((usize, usize), Self::Edge)>;
+            type Edge = f64;
+        }
+
+        let graph = <MeshGraph<Crease> as FromEdgeEncoding<Decoder>>::from_edge_encoding(
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            vec![(vec![0, 1, 2, 3], ())],
+            vec![((2, 3), true)],
+            // The order of vertices is not significant for edges.
+            vec![((1, 0), 0.5)],
+        )
+        .unwrap();
+
+        assert_eq!(1, graph.arcs().filter(|arc| arc.data).count());
+        assert_eq!(1, graph.edges().filter(|edge| edge.data == 0.5).count());
+        assert_eq!(4, graph.edge_count());
+
+        // Arcs and edges must exist in the topology formed by faces.
+        assert_eq!(
+            Some(GraphError::TopologyNotFound),
+            <MeshGraph<Crease> as FromEdgeEncoding<Decoder>>::from_edge_encoding(
+                vec![
+                    Point3::new(0.0, 0.0, 0.0),
+                    Point3::new(1.0, 0.0, 0.0),
+                    Point3::new(1.0, 1.0, 0.0),
+                    Point3::new(0.0, 1.0, 0.0),
+                ],
+                vec![(vec![0, 1, 2, 3], ())],
+                vec![],
+                vec![((0, 2), 1.0)],
+            )
+            .err()
+        );
+
+        // Faces must refer to decoded vertices.
+        assert_eq!(
+            Some(GraphError::TopologyNotFound),
+            <MeshGraph<Crease> as FromEdgeEncoding<Decoder>>::from_edge_encoding(
+                vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)],
+                vec![(vec![0, 1, 2], ())],
+                vec![],
+                vec![],
+            )
+            .err()
+        );
+    }
 }
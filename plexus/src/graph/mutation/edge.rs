@@ -1,20 +1,23 @@
 use fool::and;
+use smallvec::SmallVec;
+use std::collections::HashSet;
+use std::mem;
 use std::ops::{Deref, DerefMut};
 
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::{AsStorage, Fuse, Storage};
-use crate::entity::view::{Bind, ClosedView, Rebind};
+use crate::entity::view::{Bind, ClosedView, Rebind, Unbind};
 use crate::graph::core::Core;
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcView, Edge, EdgeKey};
-use crate::graph::face::{Face, FaceKey};
+use crate::graph::face::{Face, FaceKey, FaceView};
 use crate::graph::mutation::face::{self, FaceInsertCache, FaceRemoveCache};
 use crate::graph::mutation::vertex::{self, VertexMutation};
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::GraphError;
 use crate::transact::Transact;
-use crate::IteratorExt as _;
+use crate::{DynamicArity, IteratorExt as _};
 
 pub type CompositeEdgeKey = (EdgeKey, (ArcKey, ArcKey));
 pub type CompositeEdge<G> = (Edge<G>, (Arc<G>, Arc<G>));
@@ -81,6 +84,26 @@ where
         self.with_arc_mut(ab, |arc| arc.face.take())
     }
 
+    pub fn replace_arc_data(&mut self, ab: ArcKey, data: G::Arc) -> Result<G::Arc, GraphError> {
+        self.with_arc_mut(ab, |arc| mem::replace(&mut arc.data, data))
+    }
+
+    pub fn replace_edge_data(&mut self, ab: ArcKey, data: G::Edge) -> Result<G::Edge, GraphError> {
+        let ab_ba = self
+            .storage
+            .0
+            .get(&ab)
+            .ok_or_else(|| GraphError::TopologyNotFound)?
+            .edge
+            .ok_or_else(|| GraphError::TopologyMalformed)?;
+        let edge = self
+            .storage
+            .1
+            .get_mut(&ab_ba)
+            .ok_or_else(|| GraphError::TopologyMalformed)?;
+        Ok(mem::replace(&mut edge.data, data))
+    }
+
     fn with_arc_mut<T, F>(&mut self, ab: ArcKey, mut f: F) -> Result<T, GraphError>
     where
         F: FnMut(&mut Arc<G>) -> T,
@@ -379,6 +402,319 @@ impl ArcExtrudeCache {
     }
 }
 
+pub struct EdgeCollapseCache {
+    a: VertexKey,
+    b: VertexKey,
+    // Arcs with the source or destination vertex A.
+    arcs: HashSet<ArcKey>,
+    // Faces adjacent to A or B and their perimeters.
+    faces: Vec<(FaceKey, SmallVec<[VertexKey; 4]>)>,
+}
+
+impl EdgeCollapseCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Parametric,
+    {
+        let (storage, ab) = arc.unbind();
+        EdgeCollapseCache::from_storage(storage, ab)
+    }
+
+    // This function uses the reachable API so that collapses can be computed
+    // within a mutation. See `simplify`.
+    pub fn from_storage<B>(storage: B, ab: ArcKey) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Parametric,
+    {
+        let storage = storage.reborrow();
+        let arc = ArcView::bind(storage, ab).ok_or_else(|| GraphError::TopologyNotFound)?;
+        let opposite = arc
+            .to_ref()
+            .into_reachable_opposite_arc()
+            .ok_or_else(|| GraphError::TopologyMalformed)?;
+        let (a, b) = ab.into();
+        let source = VertexView::bind(storage, a).ok_or_else(|| GraphError::TopologyMalformed)?;
+        let destination =
+            VertexView::bind(storage, b).ok_or_else(|| GraphError::TopologyMalformed)?;
+        let is_boundary_vertex = |vertex: &VertexView<_>| {
+            vertex
+                .reachable_outgoing_arcs()
+                .chain(vertex.reachable_incoming_arcs())
+                .any(|arc| arc.is_boundary_arc())
+        };
+        let perimeter = |abc| -> Result<SmallVec<[VertexKey; 4]>, GraphError> {
+            let mut arc: ArcView<_> = FaceView::bind(storage, abc)
+                .and_then(|face| face.into_reachable_arc())
+                .ok_or_else(|| GraphError::TopologyMalformed)?;
+            let start = arc.key();
+            let mut perimeter = SmallVec::new();
+            loop {
+                let (a, _) = arc.key().into();
+                perimeter.push(a);
+                arc = arc
+                    .into_reachable_next_arc()
+                    .ok_or_else(|| GraphError::TopologyMalformed)?;
+                if arc.key() == start {
+                    break Ok(perimeter);
+                }
+            }
+        };
+        // Collapsing an interior edge with vertices on boundaries pinches the
+        // surface.
+        if !(arc.is_boundary_arc() || opposite.is_boundary_arc())
+            && is_boundary_vertex(&source)
+            && is_boundary_vertex(&destination)
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        // Check the link condition. The only vertices that may be adjacent to
+        // both A and B are the vertices opposite the edge in adjacent
+        // triangles. Otherwise, the collapse would join distinct edges or
+        // faces and the graph would no longer be manifold.
+        let mut expected = HashSet::new();
+        for abc in arc.face.into_iter().chain(opposite.face) {
+            let perimeter = perimeter(abc)?;
+            if perimeter.len() == 3 {
+                expected.extend(perimeter.into_iter().filter(|key| *key != a && *key != b));
+            }
+        }
+        let adjacent = source
+            .reachable_outgoing_arcs()
+            .map(|arc| {
+                let (_, x): (VertexKey, VertexKey) = arc.key().into();
+                x
+            })
+            .collect::<HashSet<_>>();
+        if destination
+            .reachable_outgoing_arcs()
+            .map(|arc| {
+                let (_, x): (VertexKey, VertexKey) = arc.key().into();
+                x
+            })
+            .filter(|key| adjacent.contains(key))
+            .any(|key| !expected.contains(&key))
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        let mut faces = Vec::<(FaceKey, SmallVec<[_; 4]>)>::new();
+        for abc in source
+            .reachable_outgoing_arcs()
+            .chain(destination.reachable_outgoing_arcs())
+            .flat_map(|arc| arc.face)
+        {
+            if !faces.iter().any(|(key, _)| *key == abc) {
+                faces.push((abc, perimeter(abc)?));
+            }
+        }
+        // Collapsing the edge must not remove all adjacent faces.
+        if faces.iter().all(|(_, perimeter)| {
+            perimeter.len() <= 3 && perimeter.contains(&a) && perimeter.contains(&b)
+        }) {
+            return Err(GraphError::TopologyConflict);
+        }
+        let arcs = source
+            .reachable_outgoing_arcs()
+            .flat_map(|arc| {
+                let ab = arc.key();
+                Some(ab).into_iter().chain(Some(ab.into_opposite()))
+            })
+            .collect();
+        Ok(EdgeCollapseCache { a, b, arcs, faces })
+    }
+
+    pub fn faces(&self) -> impl '_ + Iterator<Item = &[VertexKey]> {
+        self.faces.iter().map(|(_, perimeter)| perimeter.as_slice())
+    }
+}
+
+pub struct EdgeFlipCache {
+    ab: ArcKey,
+    // Vertices opposite the edge in the triangles of its arcs.
+    c: VertexKey,
+    d: VertexKey,
+    abc: FaceKey,
+    bad: FaceKey,
+}
+
+impl EdgeFlipCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let opposite = arc.opposite_arc();
+        let (abc, bad) = match (arc.face(), opposite.face()) {
+            (Some(abc), Some(bad)) => (abc, bad),
+            _ => return Err(GraphError::TopologyNotFound),
+        };
+        for face in [abc, bad].iter() {
+            if face.arity() != 3 {
+                return Err(GraphError::ArityConflict {
+                    expected: 3,
+                    actual: face.arity(),
+                });
+            }
+        }
+        let c = arc.next_arc().destination_vertex();
+        let d = opposite.next_arc().destination_vertex();
+        // The flipped edge must not already exist.
+        if c.key() == d.key() || c.adjacent_vertices().keys().any(|key| key == d.key()) {
+            return Err(GraphError::TopologyConflict);
+        }
+        Ok(EdgeFlipCache {
+            ab: arc.key(),
+            c: c.key(),
+            d: d.key(),
+            abc: abc.key(),
+            bad: bad.key(),
+        })
+    }
+}
+
+pub struct EdgeBevelCache {
+    a: VertexKey,
+    b: VertexKey,
+    // Corners of the strip face that replaces the edge followed by the corners
+    // of any faces at the ends of the strip.
+    corners: Vec<Vec<(VertexKey, VertexKey)>>,
+    // Faces adjacent to A or B and their perimeters.
+    faces: Vec<(FaceKey, SmallVec<[VertexKey; 4]>)>,
+}
+
+impl EdgeBevelCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let opposite = arc.opposite_arc();
+        let (abc, bad) = match (arc.face(), opposite.face()) {
+            (Some(abc), Some(bad)) => (abc.key(), bad.key()),
+            _ => return Err(GraphError::TopologyNotFound),
+        };
+        let (a, b) = arc.key().into();
+        // Vertices adjacent to the edge in the faces of its arcs. The strip
+        // face is formed from corners placed along the edges to these
+        // vertices.
+        let (c, d) = (
+            arc.next_arc().destination_vertex().key(),
+            arc.previous_arc().source_vertex().key(),
+        );
+        let (e, f) = (
+            opposite.next_arc().destination_vertex().key(),
+            opposite.previous_arc().source_vertex().key(),
+        );
+        let chains = (
+            vertex::chain(arc.source_vertex(), &[abc, bad])?,
+            vertex::chain(arc.destination_vertex(), &[abc, bad])?,
+        );
+        // The faces at the ends of the strip must share an edge with the strip.
+        for (chain, first, last) in [(&chains.0, d, e), (&chains.1, f, c)].iter() {
+            if !chain.is_empty() && (chain.first() != Some(first) || chain.last() != Some(last)) {
+                return Err(GraphError::TopologyConflict);
+            }
+        }
+        let mut faces = Vec::<(FaceKey, SmallVec<[_; 4]>)>::new();
+        for face in arc
+            .source_vertex()
+            .adjacent_faces()
+            .chain(arc.destination_vertex().adjacent_faces())
+        {
+            if !faces.iter().any(|(key, _)| *key == face.key()) {
+                faces.push((face.key(), face.adjacent_vertices().keys().collect()));
+            }
+        }
+        // Insert faces at the ends of the strip if the chains of adjacent
+        // vertices span more than an edge.
+        let mut corners = vec![vec![(a, e), (b, f), (b, c), (a, d)]];
+        for (c, chain) in [(a, chains.0), (b, chains.1)].iter() {
+            if chain.len() > 2 {
+                corners.push(chain.iter().map(|d| (*c, *d)).collect());
+            }
+        }
+        vertex::validate_corners(&[a, b], &faces, corners.iter().map(Vec::as_slice))?;
+        Ok(EdgeBevelCache {
+            a,
+            b,
+            corners,
+            faces,
+        })
+    }
+}
+
+pub struct EdgeRingCutCache {
+    // Splits of the edges across the ring in order.
+    splits: Vec<EdgeSplitCache>,
+    // Quadrilaterals between consecutive edges in the ring.
+    faces: Vec<FaceKey>,
+    is_closed: bool,
+}
+
+impl EdgeRingCutCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Edge<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let (keys, is_closed) = arc.edge_ring_arcs();
+        // The face of each arc in the ring is the quadrilateral that leads to
+        // the next arc. The face of the last arc in an open ring is not cut.
+        let n = if is_closed {
+            keys.len()
+        }
+        else {
+            keys.len() - 1
+        };
+        if n == 0 {
+            return Err(GraphError::TopologyNotFound);
+        }
+        let mut splits = Vec::with_capacity(keys.len());
+        let mut faces = Vec::with_capacity(n);
+        for (index, ab) in keys.into_iter().enumerate() {
+            let arc = Rebind::<_, ArcView<_>>::rebind(arc.to_ref(), ab)
+                .ok_or_else(|| GraphError::TopologyNotFound)?;
+            if index < n {
+                let abc = arc
+                    .face()
+                    .map(|face| face.key())
+                    .ok_or_else(|| GraphError::TopologyMalformed)?;
+                // A quadrilateral may only be cut once.
+                if faces.contains(&abc) {
+                    return Err(GraphError::TopologyConflict);
+                }
+                faces.push(abc);
+            }
+            splits.push(EdgeSplitCache::from_arc(arc)?);
+        }
+        Ok(EdgeRingCutCache {
+            splits,
+            faces,
+            is_closed,
+        })
+    }
+}
+
 pub fn get_or_insert_with<M, N, F>(
     mut mutation: N,
     endpoints: (VertexKey, VertexKey),
@@ -502,6 +838,95 @@ where
     ))
 }
 
+// Removes an edge that is not adjacent to any faces.
+//
+// Unlike `remove`, this does not require a consistent view of the graph and so
+// can be used after faces have been removed within a mutation. The boundaries
+// on either side of the edge are joined and the leading arcs of its vertices
+// are healed. If a vertex is no longer adjacent to any arcs, then its leading
+// arc is cleared and it must be removed or reconnected before the mutation is
+// committed.
+pub fn remove_faceless<M, N>(
+    mut mutation: N,
+    ab: ArcKey,
+) -> Result<CompositeEdge<Data<M>>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    let (a, b) = ab.into();
+    let ba = ab.into_opposite();
+    let (xa, bx, ab_ba) = {
+        let arc = mutation
+            .as_mut()
+            .storage
+            .0
+            .get(&ab)
+            .ok_or_else(|| GraphError::TopologyNotFound)?;
+        if arc.face.is_some() {
+            return Err(GraphError::TopologyConflict);
+        }
+        (arc.previous, arc.next, arc.edge)
+    };
+    let (xb, ax) = {
+        let arc = mutation
+            .as_mut()
+            .storage
+            .0
+            .get(&ba)
+            .ok_or_else(|| GraphError::TopologyMalformed)?;
+        if arc.face.is_some() {
+            return Err(GraphError::TopologyConflict);
+        }
+        (arc.previous, arc.next)
+    };
+    let ab_ba = ab_ba.ok_or_else(|| GraphError::TopologyMalformed)?;
+    let (xa, bx) = (
+        xa.ok_or_else(|| GraphError::TopologyMalformed)?,
+        bx.ok_or_else(|| GraphError::TopologyMalformed)?,
+    );
+    let (xb, ax) = (
+        xb.ok_or_else(|| GraphError::TopologyMalformed)?,
+        ax.ok_or_else(|| GraphError::TopologyMalformed)?,
+    );
+    // If the next arc of BA is AB, then there are no other arcs adjacent to A.
+    // Otherwise, join the arcs across the edge and use the next arc of BA as
+    // the leading arc of A. The same applies to B.
+    if ax == ab {
+        mutation.as_mut().disconnect_outgoing_arc(a)?;
+    }
+    else {
+        mutation.as_mut().connect_adjacent_arcs(xa, ax)?;
+        mutation.as_mut().connect_outgoing_arc(a, ax)?;
+    }
+    if bx == ba {
+        mutation.as_mut().disconnect_outgoing_arc(b)?;
+    }
+    else {
+        mutation.as_mut().connect_adjacent_arcs(xb, bx)?;
+        mutation.as_mut().connect_outgoing_arc(b, bx)?;
+    }
+    let edge = mutation
+        .as_mut()
+        .storage
+        .1
+        .remove(&ab_ba)
+        .ok_or_else(|| GraphError::TopologyMalformed)?;
+    let arc = mutation
+        .as_mut()
+        .storage
+        .0
+        .remove(&ab)
+        .ok_or_else(|| GraphError::TopologyMalformed)?;
+    let opposite = mutation
+        .as_mut()
+        .storage
+        .0
+        .remove(&ba)
+        .ok_or_else(|| GraphError::TopologyMalformed)?;
+    Ok((edge, (arc, opposite)))
+}
+
 pub fn split_with<M, N, F>(
     mut mutation: N,
     cache: EdgeSplitCache,
@@ -596,6 +1021,94 @@ where
     Ok(m)
 }
 
+pub fn collapse_with<M, N, F>(
+    mut mutation: N,
+    cache: EdgeCollapseCache,
+    f: F,
+) -> Result<VertexKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnOnce() -> <Data<M> as GraphData>::Vertex,
+{
+    let EdgeCollapseCache { a, b, arcs, faces } = cache;
+    // Remove the faces adjacent to A and B and re-insert them with B replaced
+    // by A. Faces that degenerate into edges are discarded.
+    let excision = face::excise(mutation.as_mut(), faces.iter().map(|(abc, _)| *abc))?;
+    let faces = faces
+        .into_iter()
+        .map(|(abc, perimeter)| {
+            let geometry = *excision
+                .face(abc)
+                .ok_or_else(|| GraphError::TopologyMalformed)?;
+            let perimeter = perimeter
+                .into_iter()
+                .map(|key| if key == b { a } else { key })
+                .perimeter()
+                .filter(|(previous, next)| previous != next)
+                .map(|(_, next)| next)
+                .collect::<SmallVec<[_; 4]>>();
+            Ok((perimeter, geometry))
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?
+        .into_iter()
+        .filter(|(perimeter, _)| perimeter.len() >= 3);
+    // Arcs of A retain their data. Arcs of B that are joined with A copy their
+    // data from the corresponding arcs of B.
+    face::fill_with(mutation.as_mut(), excision, faces, |ab| {
+        if arcs.contains(&ab) {
+            ab
+        }
+        else {
+            let (c, d) = ab.into();
+            let rekey = |key| if key == a { b } else { key };
+            (rekey(c), rekey(d)).into()
+        }
+    })?;
+    mutation.as_mut().replace_vertex_data(a, f())?;
+    Ok(a)
+}
+
+pub fn flip<M, N>(mut mutation: N, cache: EdgeFlipCache) -> Result<ArcKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    let EdgeFlipCache { ab, c, d, abc, bad } = cache;
+    let (a, b) = ab.into();
+    let excision = face::excise(mutation.as_mut(), [abc, bad].iter().cloned())?;
+    let faces = [abc, bad]
+        .iter()
+        .map(|key| {
+            excision
+                .face(*key)
+                .cloned()
+                .ok_or_else(|| GraphError::TopologyMalformed)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Each triangle retains the data of the face from which it takes its
+    // leading arc. The arcs of the flipped edge retain the data of the arcs of
+    // the original edge.
+    let (dc, cd) = ((d, c).into(), (c, d).into());
+    face::fill_with(
+        mutation.as_mut(),
+        excision,
+        vec![(vec![c, a, d], faces[0]), (vec![d, b, c], faces[1])],
+        |key| {
+            if key == dc {
+                ab
+            }
+            else if key == cd {
+                ab.into_opposite()
+            }
+            else {
+                key
+            }
+        },
+    )?;
+    Ok(dc)
+}
+
 pub fn bridge<M, N>(mut mutation: N, cache: ArcBridgeCache) -> Result<FaceKey, GraphError>
 where
     N: AsMut<Mutation<M>>,
@@ -638,3 +1151,70 @@ where
     let cache = ArcBridgeCache::from_storage(mutation.as_mut(), ab, cd)?;
     bridge(mutation, cache).map(|_| cd)
 }
+
+pub fn bevel_with<M, N, F>(
+    mut mutation: N,
+    cache: EdgeBevelCache,
+    f: F,
+) -> Result<FaceKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnMut(VertexKey, VertexKey) -> <Data<M> as GraphData>::Vertex,
+{
+    let EdgeBevelCache {
+        a,
+        b,
+        corners,
+        faces,
+    } = cache;
+    let keys = vertex::fill_corners_with(mutation.as_mut(), &[a, b], faces, corners, f)?;
+    keys.into_iter()
+        .next()
+        .ok_or_else(|| GraphError::TopologyMalformed)
+}
+
+pub fn cut_ring_with<M, N, F>(
+    mut mutation: N,
+    cache: EdgeRingCutCache,
+    mut f: F,
+) -> Result<Vec<VertexKey>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnMut(ArcKey) -> <Data<M> as GraphData>::Vertex,
+{
+    let EdgeRingCutCache {
+        splits,
+        faces,
+        is_closed,
+    } = cache;
+    // Split the edges across the ring.
+    let mut arcs = Vec::with_capacity(splits.len());
+    let mut vertices = Vec::with_capacity(splits.len() + 1);
+    for cache in splits {
+        let ab = cache.ab;
+        arcs.push((cache.a, cache.b));
+        vertices.push(split_with(mutation.as_mut(), cache, || f(ab))?);
+    }
+    // Split each quadrilateral between the vertices inserted into its edges.
+    // The arc of the next edge in the ring is oriented opposite to the
+    // quadrilateral, so its vertices are reversed.
+    let excision = face::excise(mutation.as_mut(), faces.iter().cloned())?;
+    let mut perimeters = Vec::with_capacity(faces.len() * 2);
+    for (n, abc) in faces.into_iter().enumerate() {
+        let data = *excision
+            .face(abc)
+            .ok_or_else(|| GraphError::TopologyMalformed)?;
+        let m = (n + 1) % vertices.len();
+        let ((a, b), (d, c)) = (arcs[n], arcs[m]);
+        let (p, q) = (vertices[n], vertices[m]);
+        perimeters.push((SmallVec::<[_; 4]>::from_buf([a, p, q, d]), data));
+        perimeters.push((SmallVec::<[_; 4]>::from_buf([p, b, c, q]), data));
+    }
+    face::fill_with(mutation.as_mut(), excision, perimeters, |ab| ab)?;
+    if is_closed {
+        vertices.push(vertices[0]);
+    }
+    Ok(vertices)
+}
@@ -409,6 +409,61 @@ impl FacePokeCache {
     }
 }
 
+pub struct FaceTriangulateCache {
+    triangles: Vec<[VertexKey; 3]>,
+    cache: FaceRemoveCache,
+}
+
+impl FaceTriangulateCache {
+    // Triangles are given as indices into the perimeter of the face.
+    pub fn from_face<B>(face: FaceView<B>, triangles: Vec<[usize; 3]>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let perimeter = face.adjacent_vertices().keys().collect::<Vec<_>>();
+        if triangles.len() + 2 != perimeter.len() {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let triangles = triangles
+            .into_iter()
+            .map(|[a, b, c]| {
+                let get = |index: usize| {
+                    perimeter
+                        .get(index)
+                        .cloned()
+                        .ok_or_else(|| GraphError::TopologyNotFound)
+                };
+                Ok([get(a)?, get(b)?, get(c)?])
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        // Arcs that are inserted within the face must not already exist.
+        for triangle in triangles.iter() {
+            for (a, b) in triangle.iter().cloned().perimeter() {
+                if let Some(arc) = Rebind::<_, ArcView<_>>::rebind(face.to_ref(), (a, b).into()) {
+                    if arc.face().map_or(true, |abc| abc.key() != face.key()) {
+                        return Err(GraphError::TopologyConflict);
+                    }
+                }
+            }
+        }
+        Ok(FaceTriangulateCache {
+            triangles,
+            cache: FaceRemoveCache::from_face(face)?,
+        })
+    }
+
+    pub fn arcs(&self) -> impl '_ + Iterator<Item = ArcKey> {
+        self.triangles
+            .iter()
+            .flat_map(|triangle| triangle.iter().cloned().perimeter().map(ArcKey::from))
+    }
+}
+
 pub struct FaceBridgeCache {
     source: SmallVec<[ArcKey; 4]>,
     destination: SmallVec<[ArcKey; 4]>,
@@ -468,6 +523,30 @@ impl FaceExtrudeCache {
     }
 }
 
+// Data and topology removed by `excise`.
+pub struct Excision<G>
+where
+    G: GraphData,
+{
+    vertices: HashSet<VertexKey>,
+    arcs: HashMap<ArcKey, G::Arc>,
+    edges: HashMap<ArcKey, G::Edge>,
+    faces: HashMap<FaceKey, G::Face>,
+}
+
+impl<G> Excision<G>
+where
+    G: GraphData,
+{
+    pub fn face(&self, abc: FaceKey) -> Option<&G::Face> {
+        self.faces.get(&abc)
+    }
+
+    pub fn vertices(&self) -> impl Iterator<Item = VertexKey> + '_ {
+        self.vertices.iter().cloned()
+    }
+}
+
 // TODO: Should this accept arc geometry at all?
 pub fn insert_with<M, N, F>(
     mut mutation: N,
@@ -526,6 +605,134 @@ where
     Ok(face)
 }
 
+// Removes faces along with any of their edges that are no longer adjacent to
+// a face. This leaves a hole in the graph that must be filled via `fill_with`
+// (or otherwise healed) before the mutation is committed.
+//
+// Unlike `remove`, this does not require a cache and so can be used with keys
+// that are determined within a mutation.
+pub fn excise<M, N, I>(mut mutation: N, faces: I) -> Result<Excision<Data<M>>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    I: IntoIterator<Item = FaceKey>,
+{
+    let mut excision = Excision {
+        vertices: HashSet::new(),
+        arcs: HashMap::new(),
+        edges: HashMap::new(),
+        faces: HashMap::new(),
+    };
+    let mut candidates = Vec::new();
+    for abc in faces {
+        let arcs = {
+            let core = &mutation.as_mut().to_ref_core();
+            let face = FaceView::bind(core, abc).ok_or_else(|| GraphError::TopologyNotFound)?;
+            let mut arcs = Vec::with_capacity(3);
+            let mut arc = face.into_reachable_arc();
+            while let Some(ab) = arc {
+                if arcs.first() == Some(&ab.key()) {
+                    break;
+                }
+                if arcs.len() > AsStorage::<Arc<Data<M>>>::as_storage(core).len() {
+                    return Err(GraphError::TopologyMalformed);
+                }
+                arcs.push(ab.key());
+                let (a, _) = ab.key().into();
+                excision.vertices.insert(a);
+                excision.arcs.insert(ab.key(), ab.data);
+                if let Some(ba) = ab.to_ref().into_reachable_opposite_arc() {
+                    excision.arcs.insert(ba.key(), ba.data);
+                }
+                if let Some(edge) = ab.to_ref().into_reachable_edge() {
+                    excision.edges.insert(ab.key(), edge.data);
+                    excision.edges.insert(ab.key().into_opposite(), edge.data);
+                }
+                arc = ab.into_reachable_next_arc();
+            }
+            arcs
+        };
+        let face = remove(
+            mutation.as_mut(),
+            FaceRemoveCache {
+                abc,
+                arcs: arcs.clone(),
+            },
+        )?;
+        excision.faces.insert(abc, face.data);
+        candidates.extend(arcs);
+    }
+    for ab in candidates {
+        let is_faceless = {
+            let core = &mutation.as_mut().to_ref_core();
+            // The edge may have already been removed via its opposite arc.
+            ArcView::bind(core, ab).map(|arc| {
+                arc.face.is_none()
+                    && arc
+                        .into_reachable_opposite_arc()
+                        .map_or(false, |opposite| opposite.face.is_none())
+            })
+        };
+        if let Some(true) = is_faceless {
+            edge::remove_faceless(mutation.as_mut(), ab)?;
+        }
+    }
+    Ok(excision)
+}
+
+// Inserts faces into a hole left by `excise` and removes any excised vertices
+// that are no longer adjacent to any arcs.
+//
+// The given function maps the key of each arc in the inserted faces to the key
+// of an excised arc from which its data (and the data of its edge) is copied.
+// Arcs that are not mapped to an excised arc use default data.
+pub fn fill_with<M, N, I, K, F>(
+    mut mutation: N,
+    excision: Excision<Data<M>>,
+    faces: I,
+    f: F,
+) -> Result<Vec<FaceKey>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    I: IntoIterator<Item = (K, <Data<M> as GraphData>::Face)>,
+    K: IntoIterator,
+    K::Item: Borrow<VertexKey>,
+    F: Fn(ArcKey) -> ArcKey,
+{
+    let Excision {
+        mut vertices,
+        arcs,
+        edges,
+        ..
+    } = excision;
+    let mut keys = Vec::new();
+    for (perimeter, data) in faces {
+        let perimeter = perimeter
+            .into_iter()
+            .map(|key| *key.borrow())
+            .collect::<SmallVec<[_; 4]>>();
+        vertices.extend(perimeter.iter().cloned());
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+        keys.push(insert_with(mutation.as_mut(), cache, || {
+            (Default::default(), data)
+        })?);
+        for (a, b) in perimeter.into_iter().perimeter() {
+            let ab = (a, b).into();
+            for ab in [ab, ArcKey::into_opposite(ab)].iter().cloned() {
+                if let Some(data) = arcs.get(&f(ab)) {
+                    mutation.as_mut().replace_arc_data(ab, *data)?;
+                }
+            }
+            if let Some(data) = edges.get(&f(ab)) {
+                mutation.as_mut().replace_edge_data(ab, *data)?;
+            }
+        }
+    }
+    vertex::remove_all_isolated(mutation.as_mut(), vertices)?;
+    Ok(keys)
+}
+
 pub fn split<M, N>(mut mutation: N, cache: FaceSplitCache) -> Result<ArcKey, GraphError>
 where
     N: AsMut<Mutation<M>>,
@@ -561,6 +768,25 @@ where
     Ok(c)
 }
 
+pub fn triangulate<M, N>(
+    mut mutation: N,
+    cache: FaceTriangulateCache,
+) -> Result<Vec<FaceKey>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    let FaceTriangulateCache { triangles, cache } = cache;
+    let face = remove(mutation.as_mut(), cache)?;
+    triangles
+        .into_iter()
+        .map(|triangle| {
+            let cache = FaceInsertCache::from_storage(mutation.as_mut(), &triangle)?;
+            insert_with(mutation.as_mut(), cache, || (Default::default(), face.data))
+        })
+        .collect()
+}
+
 pub fn bridge<M, N>(mut mutation: N, cache: FaceBridgeCache) -> Result<(), GraphError>
 where
     N: AsMut<Mutation<M>>,
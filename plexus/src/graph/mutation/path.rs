@@ -1,10 +1,11 @@
 use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
 
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::AsStorage;
-use crate::entity::view::Bind;
+use crate::entity::view::{Bind, ClosedView};
 use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::Arc;
+use crate::graph::edge::{Arc, ArcKey};
 use crate::graph::face::{Face, FaceKey};
 use crate::graph::mutation::face::{self, FaceInsertCache};
 use crate::graph::mutation::vertex;
@@ -40,6 +41,182 @@ impl PathExtrudeCache {
     }
 }
 
+pub struct PathExtrudeSurfaceCache {
+    // Vertices along the closed path.
+    ring: Vec<VertexKey>,
+    // Vertices within the region enclosed by the path.
+    interior: Vec<VertexKey>,
+    // Faces within the region enclosed by the path and their perimeters.
+    faces: Vec<(FaceKey, SmallVec<[VertexKey; 4]>)>,
+}
+
+impl PathExtrudeSurfaceCache {
+    pub fn from_path<B>(path: Path<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        if path.is_open() {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let cuts = path.arcs().keys().collect::<HashSet<_>>();
+        let mut ring = path.vertices().keys().collect::<Vec<_>>();
+        // The back and front of a closed path are the same vertex.
+        ring.pop();
+        // The enclosed region is formed from the faces to the left of the path
+        // (those adjacent to its arcs) and any faces that can be reached from
+        // them without crossing the path.
+        let mut candidates = path
+            .arcs()
+            .map(|arc| arc.into_face().ok_or_else(|| GraphError::TopologyMalformed))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut visited = HashSet::new();
+        let mut faces = Vec::new();
+        while let Some(face) = candidates.pop() {
+            if !visited.insert(face.key()) {
+                continue;
+            }
+            faces.push((face.key(), face.adjacent_vertices().keys().collect()));
+            for arc in face.into_adjacent_arcs() {
+                if cuts.contains(&arc.key()) {
+                    continue;
+                }
+                // If the region reaches the right of the path, then the path
+                // does not enclose it.
+                if cuts.contains(&arc.key().into_opposite()) {
+                    return Err(GraphError::TopologyConflict);
+                }
+                candidates.extend(arc.into_opposite_arc().into_face());
+            }
+        }
+        let interior = {
+            let ring = ring.iter().cloned().collect::<HashSet<_>>();
+            faces
+                .iter()
+                .flat_map(|(_, perimeter)| perimeter.iter().cloned())
+                .filter(|a| !ring.contains(a))
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect()
+        };
+        Ok(PathExtrudeSurfaceCache {
+            ring,
+            interior,
+            faces,
+        })
+    }
+}
+
+pub struct PathSplitCache {
+    // Vertices along the path that are split paired with the faces that are
+    // moved to each copy of the vertex.
+    splits: Vec<(VertexKey, Vec<FaceKey>)>,
+    // Faces adjacent to split vertices and their perimeters.
+    faces: Vec<(FaceKey, SmallVec<[VertexKey; 4]>)>,
+}
+
+impl PathSplitCache {
+    pub fn from_path<B>(path: Path<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        // Splitting along a boundary arc has no effect.
+        if path
+            .arcs()
+            .any(|arc| arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        let cuts = path
+            .arcs()
+            .flat_map(|arc| {
+                let ab = arc.key();
+                Some(ab).into_iter().chain(Some(ab.into_opposite()))
+            })
+            .collect::<HashSet<ArcKey>>();
+        let arcs = path.arcs().collect::<Vec<_>>();
+        let mut vertices = path.vertices().collect::<Vec<_>>();
+        if path.is_closed() {
+            // The back and front of a closed path are the same vertex.
+            vertices.pop();
+        }
+        let mut splits = Vec::new();
+        let mut faces = Vec::<(FaceKey, SmallVec<[_; 4]>)>::new();
+        for (n, vertex) in vertices.into_iter().enumerate() {
+            // The faces about the vertex are partitioned into fans that are
+            // separated by boundaries and the arcs of the path. Faces on the
+            // left of the path remain adjacent to the vertex and all other fans
+            // are moved to copies of the vertex.
+            let left = arcs
+                .get(n)
+                .or_else(|| arcs.last())
+                .and_then(|arc| arc.face())
+                .map(|face| face.key())
+                .ok_or_else(|| GraphError::TopologyMalformed)?;
+            let mut links = HashMap::<FaceKey, Vec<FaceKey>>::new();
+            for arc in vertex.incoming_arcs() {
+                let adjacent = (
+                    arc.face().map(|face| face.key()),
+                    arc.opposite_arc().face().map(|face| face.key()),
+                );
+                for face in arc.face().into_iter().chain(arc.opposite_arc().face()) {
+                    links.entry(face.key()).or_insert_with(Vec::new);
+                    if !faces.iter().any(|(key, _)| *key == face.key()) {
+                        faces.push((face.key(), face.adjacent_vertices().keys().collect()));
+                    }
+                }
+                if let (Some(abc), Some(bad)) = adjacent {
+                    if !cuts.contains(&arc.key()) {
+                        links.get_mut(&abc).unwrap().push(bad);
+                        links.get_mut(&bad).unwrap().push(abc);
+                    }
+                }
+            }
+            let mut fans = Vec::new();
+            let mut visited = HashSet::with_capacity(links.len());
+            for (abc, _) in faces.iter().filter(|(key, _)| links.contains_key(key)) {
+                if !visited.insert(*abc) {
+                    continue;
+                }
+                let mut fan = vec![*abc];
+                let mut n = 0;
+                while n < fan.len() {
+                    for key in links[&fan[n]].iter() {
+                        if visited.insert(*key) {
+                            fan.push(*key);
+                        }
+                    }
+                    n += 1;
+                }
+                fans.push(fan);
+            }
+            for fan in fans.into_iter().filter(|fan| !fan.contains(&left)) {
+                splits.push((vertex.key(), fan));
+            }
+        }
+        // If no vertices are split, then the path is a single interior arc.
+        // Arcs are keyed by their vertices, so such a path cannot be split.
+        if splits.is_empty() {
+            return Err(GraphError::TopologyConflict);
+        }
+        let moved = splits
+            .iter()
+            .flat_map(|(_, fan)| fan.iter().cloned())
+            .collect::<HashSet<_>>();
+        faces.retain(|(abc, _)| moved.contains(abc));
+        Ok(PathSplitCache { splits, faces })
+    }
+}
+
 pub fn extrude_contour_with<M, N, F>(
     mut mutation: N,
     cache: PathExtrudeCache,
@@ -66,3 +243,111 @@ where
         FaceInsertCache::from_storage(mutation.as_mut(), sources.into_iter().chain(destinations))?;
     face::insert_with(mutation.as_mut(), cache, Default::default)
 }
+
+pub fn extrude_surface_with<M, N, F>(
+    mut mutation: N,
+    cache: PathExtrudeSurfaceCache,
+    mut f: F,
+) -> Result<Vec<VertexKey>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnMut(VertexKey, <Data<M> as GraphData>::Vertex) -> <Data<M> as GraphData>::Vertex,
+{
+    let PathExtrudeSurfaceCache {
+        ring,
+        interior,
+        faces,
+    } = cache;
+    // Copy the vertices along the path and transform the vertices within the
+    // enclosed region. The function receives the key of the original vertex.
+    let mut origins = HashMap::with_capacity(ring.len());
+    let mut copies = HashMap::with_capacity(ring.len());
+    for a in ring.iter().cloned() {
+        let geometry = VertexView::bind(mutation.as_mut(), a)
+            .ok_or_else(|| GraphError::TopologyNotFound)?
+            .data;
+        let b = vertex::insert(mutation.as_mut(), f(a, geometry));
+        origins.insert(b, a);
+        copies.insert(a, b);
+    }
+    for a in interior {
+        let geometry = VertexView::bind(mutation.as_mut(), a)
+            .ok_or_else(|| GraphError::TopologyNotFound)?
+            .data;
+        mutation.as_mut().replace_vertex_data(a, f(a, geometry))?;
+    }
+    // Remove the faces in the enclosed region and re-insert them using the
+    // copied vertices. Insert quadrilaterals between the path and its copy.
+    let excision = face::excise(mutation.as_mut(), faces.iter().map(|(abc, _)| *abc))?;
+    let mut faces = faces
+        .into_iter()
+        .map(|(abc, perimeter)| {
+            let geometry = *excision
+                .face(abc)
+                .ok_or_else(|| GraphError::TopologyMalformed)?;
+            let perimeter = perimeter
+                .into_iter()
+                .map(|a| copies.get(&a).cloned().unwrap_or(a))
+                .collect::<SmallVec<[_; 4]>>();
+            Ok((perimeter, geometry))
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    for (a, b) in ring.iter().cloned().perimeter() {
+        faces.push((
+            SmallVec::from_slice(&[a, b, copies[&b], copies[&a]]),
+            Default::default(),
+        ));
+    }
+    // Arcs and edges in the extruded region copy their data from the
+    // corresponding arcs and edges in the original region.
+    let origin = |a| origins.get(&a).cloned().unwrap_or(a);
+    face::fill_with(mutation.as_mut(), excision, faces, |ab| {
+        let (a, b) = ab.into();
+        (origin(a), origin(b)).into()
+    })?;
+    Ok(ring.iter().chain(ring.first()).map(|a| copies[a]).collect())
+}
+
+pub fn split<M, N>(mut mutation: N, cache: PathSplitCache) -> Result<(), GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    let PathSplitCache { splits, faces } = cache;
+    // Copy split vertices and map faces to the copies.
+    let mut origins = HashMap::with_capacity(splits.len());
+    let mut copies = HashMap::new();
+    for (a, fan) in splits {
+        let geometry = VertexView::bind(mutation.as_mut(), a)
+            .ok_or_else(|| GraphError::TopologyNotFound)?
+            .data;
+        let b = vertex::insert(mutation.as_mut(), geometry);
+        origins.insert(b, a);
+        for abc in fan {
+            copies.insert((abc, a), b);
+        }
+    }
+    // Remove the faces adjacent to copies and re-insert them using the copied
+    // vertices. Data is copied into the new arcs and edges along the path.
+    let excision = face::excise(mutation.as_mut(), faces.iter().map(|(abc, _)| *abc))?;
+    let faces = faces
+        .into_iter()
+        .map(|(abc, perimeter)| {
+            let geometry = *excision
+                .face(abc)
+                .ok_or_else(|| GraphError::TopologyMalformed)?;
+            let perimeter = perimeter
+                .into_iter()
+                .map(|a| copies.get(&(abc, a)).cloned().unwrap_or(a))
+                .collect::<SmallVec<[_; 4]>>();
+            Ok((perimeter, geometry))
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    let origin = |a| origins.get(&a).cloned().unwrap_or(a);
+    face::fill_with(mutation.as_mut(), excision, faces, |ab| {
+        let (a, b) = ab.into();
+        (origin(a), origin(b)).into()
+    })?;
+    Ok(())
+}
@@ -1,13 +1,20 @@
+use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::{AsStorage, Fuse, Storage};
+use crate::entity::view::ClosedView;
 use crate::graph::core::Core;
 use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::ArcKey;
-use crate::graph::mutation::edge::{self, EdgeRemoveCache};
+use crate::graph::edge::{Arc, ArcKey};
+use crate::graph::face::{Face, FaceKey};
+use crate::graph::mutation::{edge, face};
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::GraphError;
 use crate::transact::Transact;
+use crate::IteratorExt as _;
 
 type OwnedCore<G> = Core<G, Storage<Vertex<G>>, (), (), ()>;
 type RefCore<'a, G> = Core<G, &'a Storage<Vertex<G>>, (), (), ()>;
@@ -33,11 +40,18 @@ where
     }
 
     // TODO: See `edge::split_with_cache`.
-    #[allow(dead_code)]
     pub fn disconnect_outgoing_arc(&mut self, a: VertexKey) -> Result<Option<ArcKey>, GraphError> {
         self.with_vertex_mut(a, |vertex| vertex.arc.take())
     }
 
+    pub fn replace_vertex_data(
+        &mut self,
+        a: VertexKey,
+        data: G::Vertex,
+    ) -> Result<G::Vertex, GraphError> {
+        self.with_vertex_mut(a, |vertex| mem::replace(&mut vertex.data, data))
+    }
+
     fn with_vertex_mut<T, F>(&mut self, a: VertexKey, mut f: F) -> Result<T, GraphError>
     where
         F: FnMut(&mut Vertex<G>) -> T,
@@ -94,18 +108,121 @@ where
 }
 
 pub struct VertexRemoveCache {
-    cache: Vec<EdgeRemoveCache>,
+    a: VertexKey,
+    arcs: Vec<ArcKey>,
+    faces: Vec<FaceKey>,
 }
 
 impl VertexRemoveCache {
     pub fn from_vertex<B>(vertex: VertexView<B>) -> Result<Self, GraphError>
     where
         B: Reborrow,
-        B::Target: AsStorage<Vertex<Data<B>>> + Consistent + Parametric,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        Ok(VertexRemoveCache {
+            a: vertex.key(),
+            arcs: vertex.outgoing_arcs().keys().collect(),
+            faces: vertex.adjacent_faces().keys().collect(),
+        })
+    }
+}
+
+pub struct VertexChamferCache {
+    a: VertexKey,
+    // Corners of the chamfer face.
+    corners: Vec<(VertexKey, VertexKey)>,
+    faces: Vec<(FaceKey, SmallVec<[VertexKey; 4]>)>,
+}
+
+impl VertexChamferCache {
+    pub fn from_vertex<B>(vertex: VertexView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let a = vertex.key();
+        let chain = chain(vertex.to_ref(), &[])?;
+        if chain.len() < 3 {
+            return Err(GraphError::TopologyConflict);
+        }
+        let corners = chain.into_iter().map(|b| (a, b)).collect::<Vec<_>>();
+        let faces = vertex
+            .adjacent_faces()
+            .map(|face| (face.key(), face.adjacent_vertices().keys().collect()))
+            .collect::<Vec<_>>();
+        validate_corners(&[a], &faces, Some(corners.as_slice()))?;
+        Ok(VertexChamferCache { a, corners, faces })
+    }
+}
+
+// Gets the adjacent vertices of a vertex ordered such that corners placed
+// along their edges form a face that replaces the vertex. Adjacent faces in
+// `excluded` are ignored.
+//
+// If the vertex is on a boundary or any faces are excluded, then the chain is
+// open and the face is closed by an edge between its last and first corners.
+pub fn chain<B>(vertex: VertexView<B>, excluded: &[FaceKey]) -> Result<Vec<VertexKey>, GraphError>
+where
+    B: Reborrow,
+    B::Target: AsStorage<Arc<Data<B>>>
+        + AsStorage<Face<Data<B>>>
+        + AsStorage<Vertex<Data<B>>>
+        + Consistent
+        + Parametric,
+{
+    let a = vertex.key();
+    // Each adjacent face with the perimeter `(..., p, a, q, ...)` is joined to
+    // the chamfer face by the arc from the corner at `q` to the corner at `p`.
+    let mut links = HashMap::new();
+    for face in vertex
+        .adjacent_faces()
+        .filter(|face| !excluded.contains(&face.key()))
     {
-        let _ = vertex;
-        unimplemented!()
+        let perimeter = face
+            .adjacent_vertices()
+            .keys()
+            .collect::<SmallVec<[_; 4]>>();
+        let n = perimeter
+            .iter()
+            .position(|key| *key == a)
+            .ok_or_else(|| GraphError::TopologyMalformed)?;
+        let p = perimeter[(n + perimeter.len() - 1) % perimeter.len()];
+        let q = perimeter[(n + 1) % perimeter.len()];
+        if links.insert(q, p).is_some() {
+            return Err(GraphError::TopologyConflict);
+        }
+    }
+    let targets = links.values().cloned().collect::<HashSet<_>>();
+    let start = links.keys().find(|key| !targets.contains(key));
+    let count = links.len() + if start.is_some() { 1 } else { 0 };
+    let mut chain = Vec::with_capacity(count);
+    if let Some(start) = start.or_else(|| links.keys().next()).cloned() {
+        let mut key = start;
+        chain.push(key);
+        while let Some(next) = links.get(&key).cloned() {
+            if next == start {
+                break;
+            }
+            if chain.len() >= count {
+                return Err(GraphError::TopologyMalformed);
+            }
+            chain.push(next);
+            key = next;
+        }
+    }
+    // The adjacent faces must form a single fan.
+    if chain.len() != count {
+        return Err(GraphError::TopologyConflict);
     }
+    Ok(chain)
 }
 
 pub fn insert<M, N>(mut mutation: N, geometry: <Data<M> as GraphData>::Vertex) -> VertexKey
@@ -116,6 +233,48 @@ where
     mutation.as_mut().storage.insert(Vertex::new(geometry))
 }
 
+// Removes a vertex that is not adjacent to any arcs.
+pub fn remove_isolated<M, N>(mut mutation: N, a: VertexKey) -> Result<Vertex<Data<M>>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+{
+    let vertex = mutation
+        .as_mut()
+        .storage
+        .get(&a)
+        .ok_or_else(|| GraphError::TopologyNotFound)?;
+    if vertex.arc.is_some() {
+        return Err(GraphError::TopologyConflict);
+    }
+    mutation
+        .as_mut()
+        .storage
+        .remove(&a)
+        .ok_or_else(|| GraphError::TopologyNotFound)
+}
+
+// Removes any of the given vertices that are not adjacent to any arcs. Other
+// vertices are ignored.
+pub fn remove_all_isolated<M, N, I>(mut mutation: N, vertices: I) -> Result<(), GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    I: IntoIterator<Item = VertexKey>,
+{
+    for a in vertices {
+        let is_isolated = mutation
+            .as_mut()
+            .storage
+            .get(&a)
+            .map_or(false, |vertex| vertex.arc.is_none());
+        if is_isolated {
+            remove_isolated(mutation.as_mut(), a)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn remove<M, N>(
     mut mutation: N,
     cache: VertexRemoveCache,
@@ -124,9 +283,183 @@ where
     N: AsMut<Mutation<M>>,
     M: Mutable,
 {
-    let VertexRemoveCache { cache } = cache;
-    for cache in cache {
-        edge::remove(mutation.as_mut(), cache)?;
+    let VertexRemoveCache { a, arcs, faces } = cache;
+    let vertex = *mutation
+        .as_mut()
+        .storage
+        .get(&a)
+        .ok_or_else(|| GraphError::TopologyNotFound)?;
+    // Remove adjacent faces and any edges that are no longer adjacent to a
+    // face. This removes all arcs connected to the vertex unless some of them
+    // were not adjacent to a face before the removal.
+    let excision = face::excise(mutation.as_mut(), faces)?;
+    let mut vertices = vec![a];
+    for ab in arcs {
+        if AsStorage::<Arc<Data<M>>>::as_storage(mutation.as_mut()).contains_key(&ab) {
+            let (_, b) = ab.into();
+            edge::remove_faceless(mutation.as_mut(), ab)?;
+            vertices.push(b);
+        }
+    }
+    // Remove any vertices that no longer have a leading arc, including the
+    // vertex itself.
+    remove_all_isolated(mutation.as_mut(), excision.vertices().chain(vertices))?;
+    Ok(vertex)
+}
+
+pub fn chamfer_with<M, N, F>(
+    mut mutation: N,
+    cache: VertexChamferCache,
+    mut f: F,
+) -> Result<FaceKey, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnMut(VertexKey) -> <Data<M> as GraphData>::Vertex,
+{
+    let VertexChamferCache { a, corners, faces } = cache;
+    let keys = fill_corners_with(mutation.as_mut(), &[a], faces, vec![corners], |_, b| f(b))?;
+    keys.into_iter()
+        .next()
+        .ok_or_else(|| GraphError::TopologyMalformed)
+}
+
+// Vertex in the perimeter of a face inserted by `fill_corners_with`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum CornerKey {
+    // An existing vertex that is retained.
+    Vertex(VertexKey),
+    // A corner placed along the edge from a replaced vertex toward an adjacent
+    // vertex.
+    Corner(VertexKey, VertexKey),
+}
+
+// Gets the perimeters of the faces inserted by `fill_corners_with`. The given
+// faces are re-inserted with the given vertices replaced by corners and are
+// followed by the faces formed from the given corners.
+fn corner_perimeters<'a, I>(
+    vertices: &[VertexKey],
+    faces: &[(FaceKey, SmallVec<[VertexKey; 4]>)],
+    corners: I,
+) -> Vec<SmallVec<[CornerKey; 4]>>
+where
+    I: IntoIterator<Item = &'a [(VertexKey, VertexKey)]>,
+{
+    let mut perimeters = faces
+        .iter()
+        .map(|(_, perimeter)| {
+            let mut keys = SmallVec::<[_; 4]>::with_capacity(perimeter.len() + vertices.len());
+            for (n, a) in perimeter.iter().cloned().enumerate() {
+                if vertices.contains(&a) {
+                    let p = perimeter[(n + perimeter.len() - 1) % perimeter.len()];
+                    let q = perimeter[(n + 1) % perimeter.len()];
+                    for b in [p, q].iter().cloned() {
+                        if !vertices.contains(&b) {
+                            keys.push(CornerKey::Corner(a, b));
+                        }
+                    }
+                }
+                else {
+                    keys.push(CornerKey::Vertex(a));
+                }
+            }
+            keys
+        })
+        .collect::<Vec<_>>();
+    perimeters.extend(corners.into_iter().map(|face| {
+        face.iter()
+            .map(|(a, b)| CornerKey::Corner(*a, *b))
+            .collect()
+    }));
+    perimeters
+}
+
+// Determines if the faces inserted by `fill_corners_with` can be inserted.
+// Each face must be a ring of at least three distinct vertices and no arc may
+// be shared by more than one face.
+//
+// This allows caches to reject conflicts before a graph is mutated. Arcs
+// between retained vertices are only ever shared by the faces that are
+// removed, so no other faces need to be examined.
+pub fn validate_corners<'a, I>(
+    vertices: &[VertexKey],
+    faces: &[(FaceKey, SmallVec<[VertexKey; 4]>)],
+    corners: I,
+) -> Result<(), GraphError>
+where
+    I: IntoIterator<Item = &'a [(VertexKey, VertexKey)]>,
+{
+    let mut arcs = HashSet::new();
+    for perimeter in corner_perimeters(vertices, faces, corners) {
+        let keys = perimeter.iter().collect::<HashSet<_>>();
+        if perimeter.len() < 3 || keys.len() != perimeter.len() {
+            return Err(GraphError::TopologyConflict);
+        }
+        for ab in perimeter.into_iter().perimeter() {
+            if !arcs.insert(ab) {
+                return Err(GraphError::TopologyConflict);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Removes the given faces and re-inserts them with the given vertices replaced
+// by corners along their edges. Corners are identified by a vertex and the
+// adjacent vertex toward which they are placed and their data is determined by
+// the given function. Finally, faces are inserted from the given corners.
+//
+// The faces and corners should be checked with `validate_corners` beforehand.
+//
+// Returns the keys of the faces inserted from corners.
+pub fn fill_corners_with<M, N, F>(
+    mut mutation: N,
+    vertices: &[VertexKey],
+    faces: Vec<(FaceKey, SmallVec<[VertexKey; 4]>)>,
+    corners: Vec<Vec<(VertexKey, VertexKey)>>,
+    mut f: F,
+) -> Result<Vec<FaceKey>, GraphError>
+where
+    N: AsMut<Mutation<M>>,
+    M: Mutable,
+    F: FnMut(VertexKey, VertexKey) -> <Data<M> as GraphData>::Vertex,
+{
+    let mut inserted = HashMap::new();
+    let mut origins = HashMap::new();
+    let excision = face::excise(mutation.as_mut(), faces.iter().map(|(abc, _)| *abc))?;
+    let n = faces.len();
+    let mut perimeters = Vec::with_capacity(n + corners.len());
+    for (index, perimeter) in corner_perimeters(vertices, &faces, corners.iter().map(Vec::as_slice))
+        .into_iter()
+        .enumerate()
+    {
+        let data = if let Some((abc, _)) = faces.get(index) {
+            *excision
+                .face(*abc)
+                .ok_or_else(|| GraphError::TopologyMalformed)?
+        }
+        else {
+            Default::default()
+        };
+        let keys = perimeter
+            .into_iter()
+            .map(|key| match key {
+                CornerKey::Vertex(a) => a,
+                CornerKey::Corner(a, b) => *inserted.entry((a, b)).or_insert_with(|| {
+                    let c = insert(mutation.as_mut(), f(a, b));
+                    origins.insert(c, a);
+                    c
+                }),
+            })
+            .collect::<SmallVec<[_; 4]>>();
+        perimeters.push((keys, data));
     }
-    unimplemented!()
+    // Arcs and edges between corners and their adjacent vertices copy their
+    // data from the arcs and edges that they replace.
+    let origin = |a| origins.get(&a).cloned().unwrap_or(a);
+    let mut keys = face::fill_with(mutation.as_mut(), excision, perimeters, |ab| {
+        let (a, b) = ab.into();
+        (origin(a), origin(b)).into()
+    })?;
+    Ok(keys.split_off(n))
 }
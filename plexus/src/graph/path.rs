@@ -11,7 +11,9 @@ use crate::geometry::Metric;
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcView, Edge};
 use crate::graph::face::{Face, FaceView, Ring};
-use crate::graph::mutation::path::{self, PathExtrudeCache};
+use crate::graph::mutation::path::{
+    self, PathExtrudeCache, PathExtrudeSurfaceCache, PathSplitCache,
+};
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::{GraphError, OptionExt as _, ResultExt as _, Selector};
@@ -490,13 +492,87 @@ where
             .expect_consistent())
     }
 
-    /// Extrudes the surface of a closed path.
+    // This is exposed via `MeshGraph::split_at_path`.
+    pub(in crate::graph) fn split_graph(self) -> Result<(), GraphError> {
+        let cache = PathSplitCache::from_path(self.to_ref())?;
+        let Path { storage, .. } = self;
+        Mutation::replace(storage, Default::default())
+            .commit_with(|mutation| path::split(mutation, cache))
+            .map(|_| ())
+    }
+
+    /// Extrudes the surface enclosed by a closed path.
+    ///
+    /// The enclosed surface is formed from the faces to the left of the path
+    /// (those adjacent to its arcs) and any faces that can be reached from
+    /// them without crossing the path. Extruding the surface copies the
+    /// vertices along the path, moves the enclosed faces to these copies, and
+    /// inserts quadrilaterals between the path and its copy. The data of the
+    /// copied vertices and any vertices within the enclosed surface is
+    /// transformed using the given function.
+    ///
+    /// Returns the extruded path, which is the copy of the path that bounds
+    /// the extruded surface.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is open, any arc in the path has no face,
+    /// or the path does not enclose a surface.
+    ///
+    /// # Examples
+    ///
+    /// Extruding a region of a grid:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph = MeshGraph::<E3>::from_raw_buffers(
+    ///     vec![Tetragon::new(0usize, 1, 4, 3), Tetragon::new(1, 2, 5, 4)],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (2.0, 0.0, 0.0),
+    ///         (0.0, 1.0, 0.0),
+    ///         (1.0, 1.0, 0.0),
+    ///         (2.0, 1.0, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let keys = graph
+    ///     .faces()
+    ///     .nth(0)
+    ///     .unwrap()
+    ///     .adjacent_vertices()
+    ///     .keys()
+    ///     .collect::<Vec<_>>();
+    /// let path = graph
+    ///     .path_mut(keys.iter().chain(keys.first()))
+    ///     .unwrap()
+    ///     .extrude_surface_with(|position| position + nalgebra::Vector3::z())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(4, path.arcs().count());
+    /// ```
     pub fn extrude_surface_with<F>(self, f: F) -> Result<Self, GraphError>
     where
         F: Fn(G::Vertex) -> G::Vertex,
     {
-        let _ = f;
-        todo!()
+        let cache = PathExtrudeSurfaceCache::from_path(self.to_ref())?;
+        let Path { storage, .. } = self;
+        Ok(Mutation::replace(storage, Default::default())
+            .commit_with(|mutation| {
+                path::extrude_surface_with(mutation, cache, |_, geometry| f(geometry))
+            })
+            .map(|(storage, keys)| Path::bind(storage, keys).expect_consistent())
+            .expect_consistent())
     }
 }
 
@@ -542,9 +618,9 @@ where
 
 #[cfg(test)]
 mod tests {
-    use nalgebra::Point2;
+    use nalgebra::{Point2, Point3, Vector3};
 
-    use crate::graph::{ClosedView, MeshGraph, Selector};
+    use crate::graph::{ClosedView, GraphError, MeshGraph, Selector};
     use crate::prelude::*;
     use crate::primitive::{Tetragon, Trigon};
     use crate::IteratorExt;
@@ -552,6 +628,7 @@ mod tests {
     use Selector::ByKey;
 
     type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
 
     #[test]
     fn open_close() {
@@ -633,4 +710,83 @@ mod tests {
         assert_eq!(left.arcs().count(), 1);
         assert_eq!(right.arcs().count(), 1);
     }
+
+    #[test]
+    fn extrude_surface() {
+        // Construct a grid of 4x4 quadrilaterals.
+        let indices = (0..4usize)
+            .flat_map(|y| {
+                (0..4usize).map(move |x| {
+                    let i = (y * 5) + x;
+                    Tetragon::new(i, i + 1, i + 6, i + 5)
+                })
+            })
+            .collect::<Vec<_>>();
+        let positions = (0..25)
+            .map(|i| ((i % 5) as f64, (i / 5) as f64, 0.0))
+            .collect::<Vec<_>>();
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(indices, positions).unwrap();
+        // Find the path that encloses the central 2x2 quadrilaterals.
+        let keys = [
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (3, 2),
+            (3, 3),
+            (2, 3),
+            (1, 3),
+            (1, 2),
+            (1, 1),
+        ]
+        .iter()
+        .map(|&(x, y)| {
+            let position = E3::new(x as f64, y as f64, 0.0);
+            graph
+                .vertices()
+                .find(|vertex| *vertex.position() == position)
+                .unwrap()
+                .key()
+        })
+        .collect::<Vec<_>>();
+        let path = graph
+            .path_mut(keys.iter())
+            .unwrap()
+            .extrude_surface_with(|position| position + Vector3::z())
+            .unwrap();
+
+        assert!(path.is_closed());
+        assert_eq!(8, path.arcs().count());
+        assert!(path.vertices().all(|vertex| vertex.position().z == 1.0));
+        assert_eq!(33, graph.vertex_count());
+        assert_eq!(24, graph.face_count());
+        // The central vertex is moved with the enclosed surface.
+        assert_eq!(
+            9,
+            graph
+                .vertices()
+                .filter(|vertex| vertex.position().z == 1.0)
+                .count()
+        );
+        assert_eq!(16, graph.arcs().filter(|arc| arc.is_boundary_arc()).count());
+    }
+
+    #[test]
+    fn extrude_surface_open_path_error() {
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+        )
+        .unwrap();
+        let keys = graph.vertices().keys().collect::<Vec<_>>();
+
+        assert_eq!(
+            GraphError::TopologyMalformed,
+            graph
+                .path_mut(keys.iter())
+                .unwrap()
+                .extrude_surface_with(|position| position)
+                .err()
+                .unwrap()
+        );
+    }
 }
@@ -0,0 +1,523 @@
+//! Serialization of [`MeshGraph`]s.
+//!
+//! With the `serde` feature enabled, [`MeshGraph`] implements `Serialize` and
+//! `Deserialize` when its [`GraphData`] types do. Graphs are serialized as
+//! flat lists of vertices, arcs, edges, and faces (including their user data)
+//! that refer to one another by index. Deserialization rebuilds storage from
+//! these lists directly and does not insert faces, so the topology of a graph
+//! and all of its data are preserved exactly.
+//!
+//! Keys are remapped: entities are inserted in the order in which they are
+//! serialized, so vertices, edges, and faces are restored in the same order in
+//! which they are iterated before serialization. The keys of entities are
+//! serialized along with them and
+//! [`MeshGraph::deserialize_with_rekeying`] maps these keys to the keys of the
+//! same entities in the deserialized graph. Deserialization validates the
+//! topology of a graph and fails if it is inconsistent.
+//!
+//! [`GraphData`]: crate::graph::GraphData
+//! [`MeshGraph`]: crate::graph::MeshGraph
+//! [`MeshGraph::deserialize_with_rekeying`]: crate::graph::MeshGraph::deserialize_with_rekeying
+
+#![cfg(feature = "serde")]
+
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use slotmap::{DefaultKey, KeyData};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::entity::storage::{AsStorage, Fuse, OpaqueKey, Storage};
+use crate::graph::core::Core;
+use crate::graph::data::GraphData;
+use crate::graph::edge::{Arc, ArcKey, Edge, EdgeKey};
+use crate::graph::face::{Face, FaceKey};
+use crate::graph::vertex::{Vertex, VertexKey};
+use crate::graph::{GraphError, MeshGraph, SubgraphRekeying};
+
+#[derive(Deserialize, Serialize)]
+struct VertexRecord<T> {
+    key: u64,
+    data: T,
+    arc: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct ArcRecord<T> {
+    data: T,
+    source: usize,
+    destination: usize,
+    next: Option<usize>,
+    previous: Option<usize>,
+    edge: Option<usize>,
+    face: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct EdgeRecord<T> {
+    key: u64,
+    data: T,
+    arc: usize,
+}
+
+#[derive(Deserialize, Serialize)]
+struct FaceRecord<T> {
+    key: u64,
+    data: T,
+    arc: usize,
+}
+
+/// Serialized representation of a [`MeshGraph`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "MeshGraph")]
+struct Records<V, A, E, F> {
+    vertices: Vec<VertexRecord<V>>,
+    arcs: Vec<ArcRecord<A>>,
+    edges: Vec<EdgeRecord<E>>,
+    faces: Vec<FaceRecord<F>>,
+}
+
+impl<G> Records<G::Vertex, G::Arc, G::Edge, G::Face>
+where
+    G: GraphData,
+{
+    fn from_graph(graph: &MeshGraph<G>) -> Self {
+        let vertices = index_keys(AsStorage::<Vertex<G>>::as_storage(graph).keys());
+        let arcs = index_keys(AsStorage::<Arc<G>>::as_storage(graph).keys());
+        let edges = index_keys(AsStorage::<Edge<G>>::as_storage(graph).keys());
+        let faces = index_keys(AsStorage::<Face<G>>::as_storage(graph).keys());
+        Records {
+            vertices: AsStorage::<Vertex<G>>::as_storage(graph)
+                .iter()
+                .map(|(key, vertex)| VertexRecord {
+                    key: into_ffi(key),
+                    data: vertex.data,
+                    arc: vertex.arc.map(|key| arcs[&key]),
+                })
+                .collect(),
+            arcs: AsStorage::<Arc<G>>::as_storage(graph)
+                .iter()
+                .map(|(key, arc)| {
+                    let (source, destination) = key.into();
+                    ArcRecord {
+                        data: arc.data,
+                        source: vertices[&source],
+                        destination: vertices[&destination],
+                        next: arc.next.map(|key| arcs[&key]),
+                        previous: arc.previous.map(|key| arcs[&key]),
+                        edge: arc.edge.map(|key| edges[&key]),
+                        face: arc.face.map(|key| faces[&key]),
+                    }
+                })
+                .collect(),
+            edges: AsStorage::<Edge<G>>::as_storage(graph)
+                .iter()
+                .map(|(key, edge)| EdgeRecord {
+                    key: into_ffi(key),
+                    data: edge.data,
+                    arc: arcs[&edge.arc],
+                })
+                .collect(),
+            faces: AsStorage::<Face<G>>::as_storage(graph)
+                .iter()
+                .map(|(key, face)| FaceRecord {
+                    key: into_ffi(key),
+                    data: face.data,
+                    arc: arcs[&face.arc],
+                })
+                .collect(),
+        }
+    }
+
+    fn into_graph(self) -> Result<(MeshGraph<G>, SubgraphRekeying), GraphError> {
+        let Records {
+            vertices: vertex_records,
+            arcs: arc_records,
+            edges: edge_records,
+            faces: face_records,
+        } = self;
+        let mut vertices = Storage::<Vertex<G>>::new();
+        let mut arcs = Storage::<Arc<G>>::new();
+        let mut edges = Storage::<Edge<G>>::new();
+        let mut faces = Storage::<Face<G>>::new();
+        let mut rekeying = SubgraphRekeying::default();
+        let mut vertex_keys = Vec::with_capacity(vertex_records.len());
+        let mut serialized_vertex_keys = Vec::with_capacity(vertex_records.len());
+        for record in &vertex_records {
+            let key: VertexKey = vertices.insert(Vertex::new(record.data));
+            let serialized = from_ffi(record.key);
+            rekey(&mut rekeying.vertices, serialized, key)?;
+            vertex_keys.push(key);
+            serialized_vertex_keys.push(serialized);
+        }
+        let arc_keys = arc_records
+            .iter()
+            .map(|record| {
+                let key = ArcKey::from((
+                    lookup(&vertex_keys, record.source)?,
+                    lookup(&vertex_keys, record.destination)?,
+                ));
+                let serialized = ArcKey::from((
+                    lookup(&serialized_vertex_keys, record.source)?,
+                    lookup(&serialized_vertex_keys, record.destination)?,
+                ));
+                rekey(&mut rekeying.arcs, serialized, key)?;
+                Ok(key)
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+        let mut edge_keys = Vec::with_capacity(edge_records.len());
+        for record in edge_records {
+            let key: EdgeKey = edges.insert(Edge::new(lookup(&arc_keys, record.arc)?, record.data));
+            rekey(&mut rekeying.edges, from_ffi(record.key), key)?;
+            edge_keys.push(key);
+        }
+        let mut face_keys = Vec::with_capacity(face_records.len());
+        for record in face_records {
+            let key: FaceKey = faces.insert(Face::new(lookup(&arc_keys, record.arc)?, record.data));
+            rekey(&mut rekeying.faces, from_ffi(record.key), key)?;
+            face_keys.push(key);
+        }
+        for (record, key) in vertex_records.into_iter().zip(vertex_keys.iter()) {
+            let arc = record
+                .arc
+                .map(|index| lookup(&arc_keys, index))
+                .transpose()?;
+            vertices.get_mut(key).expect("vertex not found").arc = arc;
+        }
+        for (record, key) in arc_records.into_iter().zip(arc_keys.iter()) {
+            let mut arc = Arc::new(record.data);
+            arc.next = record
+                .next
+                .map(|index| lookup(&arc_keys, index))
+                .transpose()?;
+            arc.previous = record
+                .previous
+                .map(|index| lookup(&arc_keys, index))
+                .transpose()?;
+            arc.edge = record
+                .edge
+                .map(|index| lookup(&edge_keys, index))
+                .transpose()?;
+            arc.face = record
+                .face
+                .map(|index| lookup(&face_keys, index))
+                .transpose()?;
+            if arcs.insert_with_key(*key, arc).is_some() {
+                // Arcs are keyed by their vertices, so two records with the
+                // same source and destination cannot be distinguished.
+                return Err(GraphError::TopologyConflict);
+            }
+        }
+        validate(&vertices, &arcs, &edges, &faces)?;
+        let graph = Core::empty()
+            .fuse(vertices)
+            .fuse(arcs)
+            .fuse(edges)
+            .fuse(faces)
+            .into();
+        Ok((graph, rekeying))
+    }
+}
+
+impl<G> MeshGraph<G>
+where
+    G: GraphData,
+{
+    /// Deserializes a graph and maps keys into it.
+    ///
+    /// The graph is paired with a [`SubgraphRekeying`] that maps the keys of
+    /// its entities before serialization to their keys in the deserialized
+    /// graph.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data cannot be deserialized, if its topology is
+    /// inconsistent, or if it gives the same key to more than one entity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// # extern crate serde_json;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    ///
+    /// let json = serde_json::to_string(&graph).unwrap();
+    /// let mut deserializer = serde_json::Deserializer::from_str(&json);
+    /// let (graph, rekeying) =
+    ///     MeshGraph::<E3>::deserialize_with_rekeying(&mut deserializer).unwrap();
+    /// let face = graph.face(rekeying.face(key).unwrap()).unwrap();
+    /// ```
+    ///
+    /// [`SubgraphRekeying`]: crate::graph::SubgraphRekeying
+    pub fn deserialize_with_rekeying<'de, D>(
+        deserializer: D,
+    ) -> Result<(Self, SubgraphRekeying), D::Error>
+    where
+        D: Deserializer<'de>,
+        G::Vertex: Deserialize<'de>,
+        G::Arc: Deserialize<'de>,
+        G::Edge: Deserialize<'de>,
+        G::Face: Deserialize<'de>,
+    {
+        Records::<G::Vertex, G::Arc, G::Edge, G::Face>::deserialize(deserializer)?
+            .into_graph()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl<G> Serialize for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: Serialize,
+    G::Arc: Serialize,
+    G::Edge: Serialize,
+    G::Face: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Records::from_graph(self).serialize(serializer)
+    }
+}
+
+impl<'de, G> Deserialize<'de> for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: Deserialize<'de>,
+    G::Arc: Deserialize<'de>,
+    G::Edge: Deserialize<'de>,
+    G::Face: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize_with_rekeying(deserializer).map(|(graph, _)| graph)
+    }
+}
+
+fn index_keys<K>(keys: impl Iterator<Item = K>) -> HashMap<K, usize>
+where
+    K: Eq + Hash,
+{
+    keys.enumerate().map(|(index, key)| (key, index)).collect()
+}
+
+fn into_ffi<K>(key: K) -> u64
+where
+    K: OpaqueKey<Inner = DefaultKey>,
+{
+    KeyData::from(key.into_inner()).as_ffi()
+}
+
+fn from_ffi<K>(value: u64) -> K
+where
+    K: OpaqueKey<Inner = DefaultKey>,
+{
+    K::from_inner(KeyData::from_ffi(value).into())
+}
+
+// Maps a serialized key to the key of its deserialized entity. Serialized keys
+// must be unique, because a rekeying could otherwise map a key to the wrong
+// entity.
+fn rekey<K>(keys: &mut HashMap<K, K>, source: K, destination: K) -> Result<(), GraphError>
+where
+    K: Eq + Hash,
+{
+    if keys.insert(source, destination).is_some() {
+        Err(GraphError::TopologyConflict)
+    }
+    else {
+        Ok(())
+    }
+}
+
+fn lookup<K>(keys: &[K], index: usize) -> Result<K, GraphError>
+where
+    K: Copy,
+{
+    keys.get(index).copied().ok_or(GraphError::TopologyNotFound)
+}
+
+// Deserialized data is untrusted, so the relationships that the mutation API
+// otherwise maintains are checked before a graph is constructed.
+fn validate<G>(
+    vertices: &Storage<Vertex<G>>,
+    arcs: &Storage<Arc<G>>,
+    edges: &Storage<Edge<G>>,
+    faces: &Storage<Face<G>>,
+) -> Result<(), GraphError>
+where
+    G: GraphData,
+{
+    for (key, vertex) in vertices.iter() {
+        // As with the mutation API, every vertex must have a leading arc.
+        let arc = vertex.arc.ok_or(GraphError::TopologyMalformed)?;
+        let (source, _) = arc.into();
+        if source != key || !arcs.contains_key(&arc) {
+            return Err(GraphError::TopologyMalformed);
+        }
+    }
+    for (key, arc) in arcs.iter() {
+        let (_, destination) = key.into();
+        let opposite = arcs
+            .get(&key.into_opposite())
+            .ok_or(GraphError::TopologyMalformed)?;
+        let next = arc.next.ok_or(GraphError::TopologyMalformed)?;
+        let previous = arc.previous.ok_or(GraphError::TopologyMalformed)?;
+        let edge = arc.edge.ok_or(GraphError::TopologyMalformed)?;
+        let (source, _) = next.into();
+        if source != destination
+            || arcs.get(&next).and_then(|next| next.previous) != Some(key)
+            || arcs.get(&previous).and_then(|previous| previous.next) != Some(key)
+            || arcs.get(&next).map(|next| next.face) != Some(arc.face)
+            || opposite.edge != Some(edge)
+        {
+            return Err(GraphError::TopologyMalformed);
+        }
+        match edges.get(&edge) {
+            Some(edge) if edge.arc == key || edge.arc == key.into_opposite() => {}
+            _ => return Err(GraphError::TopologyMalformed),
+        }
+        if let Some(face) = arc.face {
+            if !faces.contains_key(&face) {
+                return Err(GraphError::TopologyMalformed);
+            }
+        }
+    }
+    for (key, face) in faces.iter() {
+        match arcs.get(&face.arc) {
+            Some(arc) if arc.face == Some(key) => {}
+            _ => return Err(GraphError::TopologyMalformed),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::{GraphData, GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub struct Weighted;
+
+    impl GraphData for Weighted {
+        type Vertex = (u64, u64, u64);
+        type Arc = u32;
+        type Edge = u32;
+        type Face = u32;
+    }
+
+    fn into_vertex(position: E3) -> (u64, u64, u64) {
+        // Cube positions are in [-0.5, 0.5].
+        let f = |x: R64| (x.into_inner() + 0.5) as u64;
+        (f(position.x), f(position.y), f(position.z))
+    }
+
+    #[test]
+    fn round_trip_topology_and_data() {
+        let mut graph: MeshGraph<Weighted> = Cube::new()
+            .polygons::<Position<E3>>()
+            .map_vertices(into_vertex)
+            .collect();
+        for (n, mut arc) in graph.arc_orphans().enumerate() {
+            arc.data = n as u32;
+        }
+        for (n, mut edge) in graph.edge_orphans().enumerate() {
+            edge.data = n as u32;
+        }
+        let json = serde_json::to_string(&graph).unwrap();
+        let other: MeshGraph<Weighted> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(graph.vertex_count(), other.vertex_count());
+        assert_eq!(graph.arc_count(), other.arc_count());
+        assert_eq!(graph.edge_count(), other.edge_count());
+        assert_eq!(graph.face_count(), other.face_count());
+        // Vertices, edges, and faces are restored in iteration order.
+        for (vertex, other) in graph.vertices().zip(other.vertices()) {
+            assert_eq!(vertex.data, other.data);
+            assert_eq!(vertex.valence(), other.valence());
+        }
+        for (edge, other) in graph.edges().zip(other.edges()) {
+            assert_eq!(edge.data, other.data);
+            assert_eq!(edge.arc().data, other.arc().data);
+        }
+        for (face, other) in graph.faces().zip(other.faces()) {
+            assert_eq!(face.arity(), other.arity());
+        }
+    }
+
+    #[test]
+    fn round_trip_keys() {
+        let mut graph: MeshGraph<Weighted> = Cube::new()
+            .polygons::<Position<E3>>()
+            .map_vertices(into_vertex)
+            .collect();
+        for (n, mut face) in graph.face_orphans().enumerate() {
+            face.data = n as u32;
+        }
+        for (n, mut arc) in graph.arc_orphans().enumerate() {
+            arc.data = n as u32;
+        }
+        let json = serde_json::to_string(&graph).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let (other, rekeying) =
+            MeshGraph::<Weighted>::deserialize_with_rekeying(&mut deserializer).unwrap();
+
+        for vertex in graph.vertices() {
+            let other = other
+                .vertex(rekeying.vertex(vertex.key()).unwrap())
+                .unwrap();
+            assert_eq!(vertex.data, other.data);
+        }
+        for arc in graph.arcs() {
+            let other = other.arc(rekeying.arc(arc.key()).unwrap()).unwrap();
+            assert_eq!(arc.data, other.data);
+            assert_eq!(rekeying.edge(arc.edge().key()), Some(other.edge().key()));
+        }
+        for face in graph.faces() {
+            let other = other.face(rekeying.face(face.key()).unwrap()).unwrap();
+            assert_eq!(face.data, other.data);
+        }
+    }
+
+    #[test]
+    fn reject_malformed_topology() {
+        let graph: MeshGraph<Weighted> = Cube::new()
+            .polygons::<Position<E3>>()
+            .map_vertices(into_vertex)
+            .collect();
+        let mut json: serde_json::Value = serde_json::to_value(&graph).unwrap();
+        // Detach an arc from its edge.
+        json["arcs"][0]["edge"] = serde_json::Value::Null;
+
+        let error = serde_json::from_value::<MeshGraph<Weighted>>(json).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains(&GraphError::TopologyMalformed.to_string()));
+    }
+}
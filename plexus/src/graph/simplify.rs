@@ -0,0 +1,710 @@
+//! Mesh simplification.
+//!
+//! This module provides quadric error metric simplification as described by
+//! Garland and Heckbert. Each vertex is associated with a quadric that
+//! measures the squared distance of a point to the planes of the faces that
+//! are adjacent to the vertex in the original graph. Edges are collapsed in
+//! order of the error of their combined quadrics until a target is reached.
+
+use num::cast;
+use num::NumCast;
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::ops::Add;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use theon::{AsPosition, AsPositionMut};
+use typenum::U3;
+
+use crate::entity::storage::AsStorageOf;
+use crate::entity::view::{Bind, ClosedView};
+use crate::graph::data::GraphData;
+use crate::graph::edge::ArcKey;
+use crate::graph::face::Face;
+use crate::graph::mutation::edge::{self, EdgeCollapseCache};
+use crate::graph::mutation::Mutation;
+use crate::graph::vertex::{VertexKey, VertexView};
+use crate::graph::{GraphError, MeshGraph, OptionExt as _, VertexPosition};
+use crate::transact::{Mutate, Transact};
+use crate::{DynamicArity, IteratorExt as _};
+
+// Weight of the planes that constrain boundaries relative to the planes of
+// faces.
+const BOUNDARY_WEIGHT: f64 = 1000.0;
+
+type Vector3 = [f64; 3];
+
+/// Placement of vertices formed by collapsing edges.
+///
+/// See [`Simplification`].
+///
+/// [`Simplification`]: crate::graph::Simplification
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Placement {
+    /// Places vertices at the position that minimizes error. Positions may
+    /// not lie on the original surface of the graph.
+    Optimal,
+    /// Places vertices at the position of one of the vertices of a collapsed
+    /// edge. Positions always lie on the original surface of the graph.
+    Endpoint,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Placement::Optimal
+    }
+}
+
+/// Parameters for simplifying a [`MeshGraph`].
+///
+/// Simplification collapses edges until the graph has no more than a target
+/// number of faces or until any further collapse would exceed a maximum
+/// error. By default, there is no target face count nor maximum error and
+/// simplification continues until no edge can be collapsed.
+///
+/// Error is measured as a sum of squared distances between a vertex and the
+/// planes of the faces in the original graph from which the vertex has been
+/// derived.
+///
+/// See [`MeshGraph::simplify`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::simplify`]: crate::graph::MeshGraph::simplify
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Simplification {
+    face_count: usize,
+    error: f64,
+    is_boundary_preserved: bool,
+    placement: Placement,
+}
+
+impl Simplification {
+    /// Simplifies until the graph has no more than the given number of faces.
+    pub fn with_face_count(mut self, face_count: usize) -> Self {
+        self.face_count = face_count;
+        self
+    }
+
+    /// Simplifies until any further collapse would exceed the given error.
+    pub fn with_error(mut self, error: f64) -> Self {
+        self.error = error;
+        self
+    }
+
+    /// Preserves boundaries.
+    ///
+    /// If `true`, then vertices on boundaries are neither removed nor moved.
+    /// Otherwise, boundaries are only constrained by their error.
+    pub fn with_boundary_preserved(mut self, is_boundary_preserved: bool) -> Self {
+        self.is_boundary_preserved = is_boundary_preserved;
+        self
+    }
+
+    /// Places vertices formed by collapsing edges using the given
+    /// [`Placement`].
+    ///
+    /// [`Placement`]: crate::graph::Placement
+    pub fn with_placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+impl Default for Simplification {
+    fn default() -> Self {
+        Simplification {
+            face_count: 0,
+            error: f64::INFINITY,
+            is_boundary_preserved: false,
+            placement: Default::default(),
+        }
+    }
+}
+
+pub(in crate::graph) fn simplify<G>(
+    graph: &mut MeshGraph<G>,
+    simplification: Simplification,
+) -> Result<(), GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    if let Some(face) = graph.faces().find(|face| face.arity() != 3) {
+        return Err(GraphError::ArityConflict {
+            expected: 3,
+            actual: face.arity(),
+        });
+    }
+    let state = State::from_graph(graph, simplification)?;
+    let mut candidates = BinaryHeap::new();
+    for edge in graph.edges() {
+        let arc = edge.into_arc();
+        candidates
+            .extend(state.candidate(arc.source_vertex().key(), arc.destination_vertex().key()));
+    }
+    collapse(graph, state, candidates)
+}
+
+// Collapses candidates until the target of the simplification is reached. All
+// collapses are applied within a single mutation. Caches are computed from the
+// mutation using the reachable API and collapses that cannot be applied are
+// skipped, so no errors are expected within the mutation. If an error does
+// occur, then the mutation is aborted and the error is returned.
+fn collapse<G>(
+    graph: &mut MeshGraph<G>,
+    mut state: State,
+    mut candidates: BinaryHeap<Candidate>,
+) -> Result<(), GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let simplification = state.simplification;
+    Mutation::replace(graph, Default::default())
+        .commit_with(|mutation| -> Result<_, GraphError> {
+            let mutation: &mut Mutation<_> = mutation.as_mut();
+            while mutation.as_storage_of::<Face<_>>().len() > simplification.face_count {
+                let candidate = if let Some(candidate) = candidates.pop() {
+                    candidate
+                }
+                else {
+                    break;
+                };
+                // Candidates are visited in order of increasing error, so no
+                // further collapses are possible once the maximum error is
+                // exceeded.
+                if candidate.error > simplification.error {
+                    break;
+                }
+                if !state.is_current(&candidate) {
+                    continue;
+                }
+                let cache = match EdgeCollapseCache::from_storage(&*mutation, candidate.arc) {
+                    Ok(cache) => cache,
+                    // Collapses that violate the link condition are skipped.
+                    Err(GraphError::TopologyConflict) => continue,
+                    Err(error) => return Err(error),
+                };
+                if state.is_folding(&cache, &candidate) {
+                    continue;
+                }
+                // Collapses with positions that cannot be represented by the
+                // scalar type of positions are skipped.
+                let position = match from_xyz(candidate.position) {
+                    Ok(position) => position,
+                    Err(_) => continue,
+                };
+                let (a, b) = candidate.arc.into();
+                let mut data = VertexView::bind(&*mutation, a)
+                    .ok_or_else(|| GraphError::TopologyNotFound)?
+                    .data;
+                *data.as_position_mut() = position;
+                edge::collapse_with(&mut *mutation, cache, move || data)?;
+                state.merge(a, b, candidate.position);
+                let keys = VertexView::bind(&*mutation, a)
+                    .ok_or_else(|| GraphError::TopologyNotFound)?
+                    .reachable_outgoing_arcs()
+                    .map(|arc| {
+                        let (_, c): (VertexKey, VertexKey) = arc.key().into();
+                        c
+                    })
+                    .collect::<Vec<_>>();
+                for c in keys {
+                    candidates.extend(state.candidate(a, c));
+                }
+            }
+            Ok(())
+        })
+        .map(|_| ())
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Quadric([f64; 10]);
+
+impl Quadric {
+    // Gets the quadric of the plane with the given unit normal $n$ and offset
+    // $d$, where points $p$ on the plane satisfy $n \cdot p + d = 0$.
+    fn from_plane(normal: Vector3, d: f64, weight: f64) -> Self {
+        let [a, b, c] = normal;
+        Quadric([
+            weight * a * a,
+            weight * a * b,
+            weight * a * c,
+            weight * a * d,
+            weight * b * b,
+            weight * b * c,
+            weight * b * d,
+            weight * c * c,
+            weight * c * d,
+            weight * d * d,
+        ])
+    }
+
+    fn error(&self, position: Vector3) -> f64 {
+        let [x, y, z] = position;
+        let q = &self.0;
+        (q[0] * x * x)
+            + (2.0 * q[1] * x * y)
+            + (2.0 * q[2] * x * z)
+            + (2.0 * q[3] * x)
+            + (q[4] * y * y)
+            + (2.0 * q[5] * y * z)
+            + (2.0 * q[6] * y)
+            + (q[7] * z * z)
+            + (2.0 * q[8] * z)
+            + q[9]
+    }
+
+    // Gets the position that minimizes the error of the quadric. Returns
+    // `None` if the quadric is singular.
+    fn minimum(&self) -> Option<Vector3> {
+        let q = &self.0;
+        let columns = [[q[0], q[1], q[2]], [q[1], q[4], q[5]], [q[2], q[5], q[7]]];
+        let b = [-q[3], -q[6], -q[8]];
+        let det = dot(columns[0], cross(columns[1], columns[2]));
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        // Solve the system using Cramer's rule.
+        let x = dot(b, cross(columns[1], columns[2])) / det;
+        let y = dot(columns[0], cross(b, columns[2])) / det;
+        let z = dot(columns[0], cross(columns[1], b)) / det;
+        Some([x, y, z])
+    }
+}
+
+impl Add for Quadric {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let mut q = self.0;
+        for (q, p) in q.iter_mut().zip(other.0.iter()) {
+            *q += *p;
+        }
+        Quadric(q)
+    }
+}
+
+struct Candidate {
+    error: f64,
+    // Arc from the vertex that is retained to the vertex that is removed.
+    arc: ArcKey,
+    position: Vector3,
+    versions: (usize, usize),
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Order candidates such that the candidate with the least error is the
+        // greatest and is popped first from a `BinaryHeap`.
+        other
+            .error
+            .partial_cmp(&self.error)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct State {
+    positions: HashMap<VertexKey, Vector3>,
+    quadrics: HashMap<VertexKey, Quadric>,
+    // Candidates are invalidated when the version of either of their vertices
+    // changes.
+    versions: HashMap<VertexKey, usize>,
+    boundaries: HashSet<VertexKey>,
+    simplification: Simplification,
+}
+
+impl State {
+    fn from_graph<G>(
+        graph: &MeshGraph<G>,
+        simplification: Simplification,
+    ) -> Result<Self, GraphError>
+    where
+        G: GraphData,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let mut positions = HashMap::with_capacity(graph.vertex_count());
+        for vertex in graph.vertices() {
+            positions.insert(vertex.key(), into_xyz(vertex.position())?);
+        }
+        let mut quadrics = HashMap::<_, Quadric>::with_capacity(graph.vertex_count());
+        let mut normals = HashMap::with_capacity(graph.face_count());
+        for face in graph.faces() {
+            let keys = face
+                .adjacent_vertices()
+                .keys()
+                .collect::<SmallVec<[_; 3]>>();
+            let (normal, area) =
+                if let Some(plane) = normal_and_area(keys.iter().map(|key| positions[key])) {
+                    plane
+                }
+                else {
+                    continue;
+                };
+            let d = -dot(normal, positions[&keys[0]]);
+            let quadric = Quadric::from_plane(normal, d, area);
+            for key in keys {
+                let entry = quadrics.entry(key).or_default();
+                *entry = *entry + quadric;
+            }
+            normals.insert(face.key(), normal);
+        }
+        // Constrain boundaries with planes that are perpendicular to the faces
+        // adjacent to boundary edges.
+        let mut boundaries = HashSet::new();
+        for arc in graph.arcs().filter(|arc| arc.is_boundary_arc()) {
+            let (a, b) = arc.key().into();
+            boundaries.insert(a);
+            boundaries.insert(b);
+            let normal = if let Some(normal) = arc
+                .opposite_arc()
+                .face()
+                .and_then(|face| normals.get(&face.key()))
+            {
+                *normal
+            }
+            else {
+                continue;
+            };
+            let ab = sub(positions[&b], positions[&a]);
+            let weight = BOUNDARY_WEIGHT * dot(ab, ab);
+            if let Some(normal) = normalize(cross(ab, normal)) {
+                let d = -dot(normal, positions[&a]);
+                let quadric = Quadric::from_plane(normal, d, weight);
+                for key in [a, b].iter() {
+                    let entry = quadrics.entry(*key).or_default();
+                    *entry = *entry + quadric;
+                }
+            }
+        }
+        let versions = positions.keys().map(|key| (*key, 0)).collect();
+        Ok(State {
+            positions,
+            quadrics,
+            versions,
+            boundaries,
+            simplification,
+        })
+    }
+
+    fn candidate(&self, a: VertexKey, b: VertexKey) -> Option<Candidate> {
+        let quadric = self.quadric(a) + self.quadric(b);
+        let (key, position) = if self.simplification.is_boundary_preserved {
+            // Vertices on boundaries are retained and do not move.
+            match (self.boundaries.contains(&a), self.boundaries.contains(&b)) {
+                (true, true) => return None,
+                (true, false) => (a, self.positions[&a]),
+                (false, true) => (b, self.positions[&b]),
+                (false, false) => self.placement(a, b, &quadric),
+            }
+        }
+        else {
+            self.placement(a, b, &quadric)
+        };
+        let arc = if key == a { (a, b) } else { (b, a) };
+        Some(Candidate {
+            error: quadric.error(position),
+            arc: arc.into(),
+            position,
+            versions: (self.versions[&arc.0], self.versions[&arc.1]),
+        })
+    }
+
+    // Chooses the retained vertex and position of a collapse. The retained
+    // vertex is the vertex with the least error at the chosen position.
+    fn placement(&self, a: VertexKey, b: VertexKey, quadric: &Quadric) -> (VertexKey, Vector3) {
+        let (pa, pb) = (self.positions[&a], self.positions[&b]);
+        let mut positions = SmallVec::<[_; 4]>::new();
+        positions.push(pa);
+        positions.push(pb);
+        if let Placement::Optimal = self.simplification.placement {
+            positions.push(scale(add(pa, pb), 0.5));
+            positions.extend(quadric.minimum());
+        }
+        let position = positions
+            .into_iter()
+            .min_by(|p, q| {
+                quadric
+                    .error(*p)
+                    .partial_cmp(&quadric.error(*q))
+                    .unwrap_or(Ordering::Equal)
+            })
+            .expect_consistent();
+        if self.quadric(a).error(position) <= self.quadric(b).error(position) {
+            (a, position)
+        }
+        else {
+            (b, position)
+        }
+    }
+
+    fn is_current(&self, candidate: &Candidate) -> bool {
+        let (a, b) = candidate.arc.into();
+        match (self.versions.get(&a), self.versions.get(&b)) {
+            (Some(va), Some(vb)) => (*va, *vb) == candidate.versions,
+            _ => false,
+        }
+    }
+
+    // Determines if collapsing the candidate would fold any remaining face
+    // adjacent to its vertices, such that the normal of the face is reversed
+    // or degenerate. The adjacent faces are read from the collapse cache.
+    fn is_folding(&self, cache: &EdgeCollapseCache, candidate: &Candidate) -> bool {
+        let (a, b) = candidate.arc.into();
+        for keys in cache.faces() {
+            if keys.contains(&a) && keys.contains(&b) {
+                continue;
+            }
+            let before = normal_and_area(keys.iter().map(|key| self.positions[key]));
+            let after = normal_and_area(keys.iter().map(|key| {
+                if *key == a || *key == b {
+                    candidate.position
+                }
+                else {
+                    self.positions[key]
+                }
+            }));
+            match (before, after) {
+                (Some((before, _)), Some((after, _))) => {
+                    if dot(before, after) <= 0.0 {
+                        return true;
+                    }
+                }
+                (_, None) => {
+                    return true;
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
+    fn merge(&mut self, a: VertexKey, b: VertexKey, position: Vector3) {
+        let quadric = self.quadric(a) + self.quadric(b);
+        self.quadrics.insert(a, quadric);
+        self.quadrics.remove(&b);
+        self.positions.insert(a, position);
+        self.positions.remove(&b);
+        *self.versions.get_mut(&a).expect_consistent() += 1;
+        self.versions.remove(&b);
+        if self.boundaries.remove(&b) {
+            self.boundaries.insert(a);
+        }
+    }
+
+    fn quadric(&self, key: VertexKey) -> Quadric {
+        self.quadrics.get(&key).cloned().unwrap_or_default()
+    }
+}
+
+// Gets the unit normal and area of a triangle. Returns `None` if the triangle
+// is degenerate.
+fn normal_and_area<I>(positions: I) -> Option<(Vector3, f64)>
+where
+    I: IntoIterator<Item = Vector3>,
+{
+    let mut positions = positions.into_iter();
+    let a = positions.next()?;
+    let b = positions.next()?;
+    let c = positions.next()?;
+    let normal = cross(sub(b, a), sub(c, a));
+    let area = dot(normal, normal).sqrt() * 0.5;
+    normalize(normal).map(|normal| (normal, area))
+}
+
+fn add(a: Vector3, b: Vector3) -> Vector3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Vector3, b: Vector3) -> Vector3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: Vector3, k: f64) -> Vector3 {
+    [a[0] * k, a[1] * k, a[2] * k]
+}
+
+fn dot(a: Vector3, b: Vector3) -> f64 {
+    (a[0] * b[0]) + (a[1] * b[1]) + (a[2] * b[2])
+}
+
+fn cross(a: Vector3, b: Vector3) -> Vector3 {
+    [
+        (a[1] * b[2]) - (a[2] * b[1]),
+        (a[2] * b[0]) - (a[0] * b[2]),
+        (a[0] * b[1]) - (a[1] * b[0]),
+    ]
+}
+
+fn normalize(a: Vector3) -> Option<Vector3> {
+    let magnitude = dot(a, a).sqrt();
+    if magnitude > 0.0 && magnitude.is_finite() {
+        Some(scale(a, 1.0 / magnitude))
+    }
+    else {
+        None
+    }
+}
+
+fn into_xyz<T>(position: &T) -> Result<Vector3, GraphError>
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let (x, y, z) = position.into_xyz();
+    Ok([
+        num_cast_scalar(x)?,
+        num_cast_scalar(y)?,
+        num_cast_scalar(z)?,
+    ])
+}
+
+fn from_xyz<T>(position: Vector3) -> Result<T, GraphError>
+where
+    T: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let [x, y, z] = position;
+    Ok(T::from_xyz(
+        num_cast_scalar(x)?,
+        num_cast_scalar(y)?,
+        num_cast_scalar(z)?,
+    ))
+}
+
+fn num_cast_scalar<T, U>(value: T) -> Result<U, GraphError>
+where
+    T: NumCast,
+    U: NumCast,
+{
+    cast::cast(value).ok_or(GraphError::Geometry)
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use num::Zero;
+
+    use std::collections::BinaryHeap;
+
+    use crate::graph::simplify::{self, State};
+    use crate::graph::{GraphError, MeshGraph, Placement, Simplification};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn simplify_to_face_count() {
+        let mut graph: MeshGraph<E3> = UvSphere::new(16, 8)
+            .polygons::<Position<E3>>() // 224 triangles.
+            .triangulate()
+            .collect();
+        graph
+            .simplify(Simplification::default().with_face_count(64))
+            .unwrap();
+
+        assert!(graph.face_count() <= 64);
+        // The graph remains closed.
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+    }
+
+    #[test]
+    fn simplify_with_preserved_boundary() {
+        // Construct a planar grid of triangles with 16 vertices, 12 of which
+        // are on the boundary.
+        let mut indices = vec![];
+        for y in 0..3u32 {
+            for x in 0..3u32 {
+                let i = (y * 4) + x;
+                indices.extend(&[i, i + 1, i + 5, i, i + 5, i + 4]);
+            }
+        }
+        let positions = (0..16)
+            .map(|i| ((i % 4) as f64, (i / 4) as f64, 0.0))
+            .collect::<Vec<_>>();
+        let mut graph =
+            MeshGraph::<E3>::from_raw_buffers_with_arity(indices, positions.clone(), 3).unwrap();
+        graph
+            .simplify(
+                Simplification::default()
+                    .with_error(1e-9)
+                    .with_boundary_preserved(true)
+                    .with_placement(Placement::Endpoint),
+            )
+            .unwrap();
+
+        assert!(graph.vertex_count() < 16);
+        // Vertices on the boundary are not moved.
+        for (x, y, z) in positions
+            .into_iter()
+            .filter(|(x, y, _)| *x == 0.0 || *x == 3.0 || *y == 0.0 || *y == 3.0)
+        {
+            let position = E3::new(x.into(), y.into(), z.into());
+            assert!(graph
+                .vertices()
+                .any(|vertex| *vertex.position() == position));
+        }
+        // Vertices remain on the plane.
+        assert!(graph
+            .vertices()
+            .all(|vertex| vertex.position().z == R64::zero()));
+    }
+
+    #[test]
+    fn simplify_collapse_error() {
+        let mut graph: MeshGraph<E3> = Cube::new()
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let state = State::from_graph(&graph, Default::default()).unwrap();
+        // Find vertices that are not adjacent, such that there is no arc
+        // between them to collapse.
+        let a = graph.vertices().nth(0).unwrap();
+        let b = graph
+            .vertices()
+            .find(|b| {
+                b.key() != a.key() && a.adjacent_vertices().all(|vertex| vertex.key() != b.key())
+            })
+            .unwrap();
+        let (a, b) = (a.key(), b.key());
+        let mut candidates = BinaryHeap::new();
+        candidates.extend(state.candidate(a, b));
+
+        assert_eq!(
+            Err(GraphError::TopologyNotFound),
+            simplify::collapse(&mut graph, state, candidates),
+        );
+    }
+
+    #[test]
+    fn simplify_non_triangular_error() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(
+            Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4
+            }),
+            graph.simplify(Default::default()),
+        );
+    }
+}
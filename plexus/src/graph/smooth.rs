@@ -0,0 +1,386 @@
+//! Mesh smoothing.
+//!
+//! This module provides Laplacian smoothing with uniform or cotangent weights
+//! and Taubin smoothing. Laplacian smoothing moves each vertex toward a
+//! weighted mean of its adjacent vertices, which shrinks meshes over many
+//! iterations. Taubin smoothing alternates each such step with a step that
+//! moves vertices away from that mean, which counteracts shrinkage.
+
+use num::NumCast;
+use std::collections::HashMap;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar};
+use theon::{AsPosition, AsPositionMut};
+
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::ArcView;
+use crate::graph::geometry::{into_f64, VertexPosition};
+use crate::graph::vertex::VertexView;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _};
+use crate::DynamicArity;
+
+/// Weights of adjacent vertices used to smooth vertices.
+///
+/// See [`Smoothing`].
+///
+/// [`Smoothing`]: crate::graph::Smoothing
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Weighting {
+    /// Weights all adjacent vertices equally. Vertices are moved toward their
+    /// centroid.
+    Uniform,
+    /// Weights adjacent vertices by the cotangents of the angles opposite the
+    /// edges that connect them. This is insensitive to the distribution of
+    /// vertices and does not move vertices within a plane, but requires a
+    /// triangle mesh.
+    Cotangent,
+}
+
+impl Default for Weighting {
+    fn default() -> Self {
+        Weighting::Uniform
+    }
+}
+
+/// Parameters for smoothing a [`MeshGraph`].
+///
+/// Each iteration of smoothing moves vertices toward a weighted mean of their
+/// adjacent vertices scaled by a factor. That is, given a factor $\lambda$ and
+/// a vertex with position $P$ and weighted mean $Q$, its position becomes
+/// $P+\lambda(Q-P)$. If Taubin smoothing is enabled, then each iteration
+/// applies a second step with a negative factor $\mu$ where $\mu<-\lambda$.
+///
+/// By default, smoothing applies one iteration with a factor of one half and
+/// uniform weights to all vertices, including those on boundaries.
+///
+/// See [`MeshGraph::smooth_with`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::smooth_with`]: crate::graph::MeshGraph::smooth_with
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Smoothing {
+    factor: f64,
+    taubin: Option<f64>,
+    iterations: usize,
+    weighting: Weighting,
+    is_boundary_pinned: bool,
+}
+
+impl Smoothing {
+    /// Scales the translation of vertices toward their weighted mean by the
+    /// given factor $\lambda$.
+    pub fn with_factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Enables Taubin smoothing with the given negative factor $\mu$.
+    ///
+    /// The magnitude of $\mu$ should be slightly larger than $\lambda$. For
+    /// example, $\lambda=0.5$ and $\mu=-0.53$.
+    pub fn with_taubin(mut self, factor: f64) -> Self {
+        self.taubin = Some(factor);
+        self
+    }
+
+    /// Applies the given number of iterations.
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Weights adjacent vertices using the given [`Weighting`].
+    ///
+    /// [`Weighting`]: crate::graph::Weighting
+    pub fn with_weighting(mut self, weighting: Weighting) -> Self {
+        self.weighting = weighting;
+        self
+    }
+
+    /// Pins boundaries.
+    ///
+    /// If `true`, then vertices on boundaries are not moved.
+    pub fn with_boundary_pinned(mut self, is_boundary_pinned: bool) -> Self {
+        self.is_boundary_pinned = is_boundary_pinned;
+        self
+    }
+}
+
+impl Default for Smoothing {
+    fn default() -> Self {
+        Smoothing {
+            factor: 0.5,
+            taubin: None,
+            iterations: 1,
+            weighting: Default::default(),
+            is_boundary_pinned: false,
+        }
+    }
+}
+
+pub(in crate::graph) fn smooth<G, F>(
+    graph: &mut MeshGraph<G>,
+    smoothing: Smoothing,
+    mut f: F,
+) -> Result<(), GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPositionMut,
+    VertexPosition<G>: EuclideanSpace,
+    F: FnMut(VertexView<&MeshGraph<G>>) -> bool,
+{
+    if let Weighting::Cotangent = smoothing.weighting {
+        if let Some(face) = graph.faces().find(|face| face.arity() != 3) {
+            return Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: face.arity(),
+            });
+        }
+    }
+    let keys = graph
+        .vertices()
+        .filter(|vertex| {
+            !(smoothing.is_boundary_pinned
+                && vertex
+                    .outgoing_arcs()
+                    .any(|arc| arc.edge().is_boundary_edge()))
+        })
+        .filter_map(|vertex| {
+            let key = vertex.key();
+            if f(vertex) {
+                Some(key)
+            }
+            else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    let factors = Some(smoothing.factor)
+        .into_iter()
+        .chain(smoothing.taubin)
+        .map(|factor| {
+            <Scalar<VertexPosition<G>> as NumCast>::from(factor).ok_or_else(|| GraphError::Geometry)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    for _ in 0..smoothing.iterations {
+        for factor in factors.iter().cloned() {
+            let mut positions = HashMap::with_capacity(keys.len());
+            for key in keys.iter().cloned() {
+                let vertex = graph.vertex(key).expect_consistent();
+                let position = *vertex.position();
+                let mean = mean(vertex, smoothing.weighting)?;
+                positions.insert(key, position + ((mean - position) * factor));
+            }
+            for mut vertex in graph.vertex_orphans() {
+                if let Some(position) = positions.remove(&vertex.key()) {
+                    *vertex.data.as_position_mut() = position;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Computes the weighted mean of the positions of the vertices adjacent to the
+// given vertex. Cotangent weights fall back to uniform weights if they are
+// degenerate.
+fn mean<G>(
+    vertex: VertexView<&MeshGraph<G>>,
+    weighting: Weighting,
+) -> Result<VertexPosition<G>, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+{
+    let mut weights = vertex
+        .outgoing_arcs()
+        .map(|arc| {
+            let weight = match weighting {
+                Weighting::Uniform => 1.0,
+                Weighting::Cotangent => cotangent_weight(arc)?,
+            };
+            Ok((weight, *arc.destination_vertex().position()))
+        })
+        .collect::<Result<Vec<_>, GraphError>>()?;
+    let sum = weights.iter().map(|(weight, _)| weight).sum::<f64>();
+    if sum <= 0.0 {
+        for (weight, _) in weights.iter_mut() {
+            *weight = 1.0;
+        }
+    }
+    let sum = weights.iter().map(|(weight, _)| weight).sum::<f64>();
+    // Cotangent weights may be negative, so the mean is computed as an affine
+    // combination of offsets from the position of the vertex rather than via
+    // `WeightedMean`.
+    let origin = *vertex.position();
+    weights
+        .into_iter()
+        .try_fold(origin, |mean, (weight, position)| {
+            let weight = <Scalar<VertexPosition<G>> as NumCast>::from(weight / sum)
+                .ok_or_else(|| GraphError::Geometry)?;
+            Ok(mean + ((position - origin) * weight))
+        })
+}
+
+// Computes half of the sum of the cotangents of the angles opposite the given
+// arc in its adjacent triangles.
+fn cotangent_weight<G>(arc: ArcView<&MeshGraph<G>>) -> Result<f64, GraphError>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+{
+    let a = *arc.source_vertex().position();
+    let b = *arc.destination_vertex().position();
+    let mut weight = 0.0;
+    for arc in [arc, arc.opposite_arc()].iter() {
+        if arc.is_boundary_arc() {
+            continue;
+        }
+        let c = *arc.next_arc().destination_vertex().position();
+        let (ca, cb) = (a - c, b - c);
+        let dot = into_f64(ca.dot(cb))?;
+        let cross = ((into_f64(ca.dot(ca))? * into_f64(cb.dot(cb))?) - (dot * dot)).sqrt();
+        // Degenerate triangles do not contribute to the weight.
+        if cross > 0.0 {
+            weight += dot / cross;
+        }
+    }
+    Ok(weight / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+
+    use crate::graph::{GraphError, MeshGraph, Smoothing, Weighting};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<f64>;
+
+    // Constructs a planar grid of triangles with 16 vertices, 12 of which are
+    // on the boundary. Interior vertices are displaced within the plane.
+    fn grid() -> MeshGraph<E3> {
+        let mut indices = vec![];
+        for y in 0..3u32 {
+            for x in 0..3u32 {
+                let i = (y * 4) + x;
+                indices.extend(&[i, i + 1, i + 5, i, i + 5, i + 4]);
+            }
+        }
+        let positions = (0..16)
+            .map(|i| {
+                let (x, y) = ((i % 4) as f64, (i / 4) as f64);
+                match i {
+                    5 => (x + 0.2, y - 0.1, 0.0),
+                    10 => (x - 0.1, y + 0.2, 0.0),
+                    _ => (x, y, 0.0),
+                }
+            })
+            .collect::<Vec<_>>();
+        MeshGraph::<E3>::from_raw_buffers_with_arity(indices, positions, 3).unwrap()
+    }
+
+    fn mean_radius(graph: &MeshGraph<E3>) -> f64 {
+        graph
+            .vertices()
+            .map(|vertex| vertex.position().coords.norm())
+            .sum::<f64>()
+            / graph.vertex_count() as f64
+    }
+
+    #[test]
+    fn smooth_taubin() {
+        let sphere = UvSphere::new(16, 16);
+        let mut laplacian: MeshGraph<E3> = sphere.polygons::<Position<E3>>().collect();
+        let mut taubin = laplacian.clone();
+        let radius = mean_radius(&laplacian);
+        laplacian
+            .smooth_with(Smoothing::default().with_iterations(8), |_| true)
+            .unwrap();
+        taubin
+            .smooth_with(
+                Smoothing::default().with_taubin(-0.53).with_iterations(8),
+                |_| true,
+            )
+            .unwrap();
+
+        // Taubin smoothing shrinks the sphere much less than Laplacian
+        // smoothing.
+        assert!((radius - mean_radius(&taubin)).abs() < (radius - mean_radius(&laplacian)) / 4.0);
+    }
+
+    #[test]
+    fn smooth_cotangent_planar() {
+        let mut graph = grid();
+        let positions = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+        graph
+            .smooth_with(
+                Smoothing::default()
+                    .with_weighting(Weighting::Cotangent)
+                    .with_boundary_pinned(true)
+                    .with_iterations(4),
+                |_| true,
+            )
+            .unwrap();
+
+        // Cotangent weights do not move vertices within a plane.
+        for (key, position) in positions {
+            let vertex = graph.vertex(key).unwrap();
+            assert!((vertex.position() - position).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn smooth_with_pinned_boundary_and_mask() {
+        let mut graph = grid();
+        let positions = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), *vertex.position()))
+            .collect::<Vec<_>>();
+        // Only move vertices with X coordinates greater than 1.5.
+        graph
+            .smooth_with(Smoothing::default().with_boundary_pinned(true), |vertex| {
+                vertex.position().x > 1.5
+            })
+            .unwrap();
+
+        let mut count = 0;
+        for (key, position) in positions {
+            let vertex = graph.vertex(key).unwrap();
+            let is_boundary = vertex
+                .outgoing_arcs()
+                .any(|arc| arc.edge().is_boundary_edge());
+            if is_boundary || position.x <= 1.5 {
+                assert_eq!(position, *vertex.position());
+            }
+            else if position != *vertex.position() {
+                count += 1;
+            }
+        }
+        assert_eq!(2, count);
+    }
+
+    #[test]
+    fn smooth_cotangent_arity_error() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(
+            Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4,
+            }),
+            graph.smooth_with(
+                Smoothing::default().with_weighting(Weighting::Cotangent),
+                |_| true,
+            ),
+        );
+    }
+}
@@ -0,0 +1,516 @@
+//! Smooth subdivision.
+//!
+//! This module provides Catmull-Clark and Loop subdivision. Subdivision
+//! computes the data of vertices as weighted means of the data of other
+//! vertices via the [`WeightedMean`] trait. Boundaries are treated as sharp
+//! creases.
+//!
+//! [`WeightedMean`]: crate::graph::WeightedMean
+
+use num::NumCast;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use theon::space::{EuclideanSpace, Scalar};
+
+use crate::entity::view::ClosedView;
+use crate::graph::data::GraphData;
+use crate::graph::edge::{ArcKey, EdgeKey, EdgeView};
+use crate::graph::mutation::{face, vertex, Mutation};
+use crate::graph::vertex::VertexKey;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _, ResultExt as _};
+use crate::transact::{Mutate, Transact};
+use crate::{DynamicArity, IteratorExt as _};
+
+/// Data that can be combined by a weighted mean.
+///
+/// Subdivision computes the data of vertices as weighted means of the data of
+/// other vertices. The weights of a mean are never negative and always sum to
+/// one.
+///
+/// This trait is implemented for types that implement [`EuclideanSpace`], so
+/// graphs with positional vertex data can be subdivided directly. Vertex data
+/// with other attributes must implement this trait to interpolate those
+/// attributes.
+///
+/// # Examples
+///
+/// Implementing `WeightedMean` for vertex data with a position and a scalar
+/// attribute:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::graph::WeightedMean;
+///
+/// #[derive(Clone, Copy)]
+/// pub struct Vertex {
+///     pub position: Point3<f64>,
+///     pub temperature: f64,
+/// }
+///
+/// impl WeightedMean for Vertex {
+///     fn weighted_mean<I>(items: I) -> Option<Self>
+///     where
+///         I: IntoIterator<Item = (f64, Self)>,
+///     {
+///         let items = items.into_iter().collect::<Vec<_>>();
+///         Some(Vertex {
+///             position: WeightedMean::weighted_mean(
+///                 items.iter().map(|(weight, vertex)| (*weight, vertex.position)),
+///             )?,
+///             temperature: items
+///                 .iter()
+///                 .map(|(weight, vertex)| weight * vertex.temperature)
+///                 .sum(),
+///         })
+///     }
+/// }
+/// ```
+///
+/// [`EuclideanSpace`]: theon::space::EuclideanSpace
+pub trait WeightedMean: Sized {
+    /// Computes the mean of the given items with the given weights.
+    ///
+    /// Returns `None` if there are no items.
+    fn weighted_mean<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (f64, Self)>;
+}
+
+impl<T> WeightedMean for T
+where
+    T: EuclideanSpace,
+{
+    fn weighted_mean<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (f64, Self)>,
+    {
+        // Points cannot be summed, so the mean is computed as an affine
+        // combination relative to the first point. Because the weights sum to
+        // one, the weight of the first point is implied.
+        let mut items = items.into_iter();
+        let (_, origin) = items.next()?;
+        Some(items.fold(origin, |mean, (weight, point)| {
+            let weight = <Scalar<T> as NumCast>::from(weight).unwrap();
+            mean + ((point - origin) * weight)
+        }))
+    }
+}
+
+pub(in crate::graph) fn catmull_clark<G, F>(graph: &mut MeshGraph<G>, mut f: F)
+where
+    G: GraphData,
+    G::Vertex: WeightedMean,
+    F: FnMut(EdgeView<&MeshGraph<G>>) -> bool,
+{
+    // Boundary edges are always sharp.
+    let creases = graph
+        .edges()
+        .map(|edge| {
+            let key = edge.key();
+            (key, edge.is_boundary_edge() || f(edge))
+        })
+        .collect::<HashMap<_, _>>();
+    // Compute face points at the centroid of each face.
+    let mut faces = HashMap::with_capacity(graph.face_count());
+    for face in graph.faces() {
+        let arity = face.arity() as f64;
+        let data = G::Vertex::weighted_mean(
+            face.adjacent_vertices()
+                .map(|vertex| (1.0 / arity, vertex.data)),
+        )
+        .expect_consistent();
+        let perimeter = face
+            .adjacent_arcs()
+            .map(|arc| (arc.source_vertex().key(), arc.edge().key()))
+            .collect::<SmallVec<[_; 4]>>();
+        faces.insert(face.key(), (data, perimeter));
+    }
+    // Compute edge points. The edge points of smooth edges are the mean of the
+    // endpoints and adjacent face points of the edge. The edge points of sharp
+    // edges are at their midpoints.
+    let mut edges = HashMap::with_capacity(graph.edge_count());
+    for edge in graph.edges() {
+        let arc = edge.arc();
+        let endpoints = arc.adjacent_vertices().map(|vertex| vertex.data);
+        let data = if creases[&edge.key()] {
+            G::Vertex::weighted_mean(endpoints.map(|data| (0.5, data)))
+        }
+        else {
+            G::Vertex::weighted_mean(
+                endpoints.map(|data| (0.25, data)).chain(
+                    arc.adjacent_faces()
+                        .map(|face| (0.25, faces[&face.key()].0)),
+                ),
+            )
+        }
+        .expect_consistent();
+        edges.insert(edge.key(), (data, arc.key()));
+    }
+    // Compute vertex points. The rule depends on the number of sharp edges
+    // that are incident to each vertex.
+    let mut vertices = Vec::with_capacity(graph.vertex_count());
+    for vertex in graph.vertices() {
+        let sharp = vertex
+            .outgoing_arcs()
+            .filter(|arc| creases[&arc.edge().key()])
+            .map(|arc| arc.destination_vertex().data)
+            .collect::<SmallVec<[_; 2]>>();
+        let data = match sharp.len() {
+            // Smooth vertices and darts.
+            0 | 1 => {
+                let n = vertex.adjacent_vertices().count() as f64;
+                let k = vertex.adjacent_faces().count() as f64;
+                G::Vertex::weighted_mean(
+                    Some(((n - 2.0) / n, vertex.data))
+                        .into_iter()
+                        .chain(
+                            vertex
+                                .adjacent_vertices()
+                                .map(|vertex| (1.0 / (n * n), vertex.data)),
+                        )
+                        .chain(
+                            vertex
+                                .adjacent_faces()
+                                .map(|face| (1.0 / (n * k), faces[&face.key()].0)),
+                        ),
+                )
+                .expect_consistent()
+            }
+            // Creases, including boundaries.
+            2 => G::Vertex::weighted_mean(
+                Some((0.75, vertex.data))
+                    .into_iter()
+                    .chain(sharp.into_iter().map(|data| (0.125, data))),
+            )
+            .expect_consistent(),
+            // Corners.
+            _ => vertex.data,
+        };
+        vertices.push((vertex.key(), data));
+    }
+    Mutation::replace(graph, Default::default())
+        .commit_with(|mutation| {
+            let mutation: &mut Mutation<_> = mutation.as_mut();
+            let excision = face::excise(&mut *mutation, faces.keys().cloned())?;
+            for (a, data) in vertices {
+                mutation.replace_vertex_data(a, data)?;
+            }
+            let (edges, origins) = insert_edge_points(&mut *mutation, edges);
+            // Insert face points and replace each face with a quadrilateral for
+            // each vertex in its perimeter.
+            let mut quadrilaterals = Vec::new();
+            for (abc, (data, perimeter)) in faces {
+                let c = vertex::insert(&mut *mutation, data);
+                let data = *excision.face(abc).expect_consistent();
+                for ((_, previous), (a, next)) in perimeter.into_iter().perimeter() {
+                    quadrilaterals.push(([a, edges[&next], c, edges[&previous]], data));
+                }
+            }
+            // Arcs between vertices and edge points retain the data of the
+            // arcs that they split and so creases are preserved.
+            face::fill_with(&mut *mutation, excision, quadrilaterals, |ab| {
+                origins.get(&ab).cloned().unwrap_or(ab)
+            })
+        })
+        .expect_consistent();
+}
+
+pub(in crate::graph) fn loop_subdivision<G>(
+    graph: &mut MeshGraph<G>,
+    iterations: usize,
+) -> Result<(), GraphError>
+where
+    G: GraphData,
+    G::Vertex: WeightedMean,
+{
+    if let Some(face) = graph.faces().find(|face| face.arity() != 3) {
+        return Err(GraphError::ArityConflict {
+            expected: 3,
+            actual: face.arity(),
+        });
+    }
+    for _ in 0..iterations {
+        loop_subdivide_once(graph);
+    }
+    Ok(())
+}
+
+fn loop_subdivide_once<G>(graph: &mut MeshGraph<G>)
+where
+    G: GraphData,
+    G::Vertex: WeightedMean,
+{
+    let faces = graph
+        .faces()
+        .map(|face| {
+            let perimeter = face
+                .adjacent_arcs()
+                .map(|arc| (arc.source_vertex().key(), arc.edge().key()))
+                .collect::<SmallVec<[_; 3]>>();
+            (face.key(), perimeter)
+        })
+        .collect::<Vec<_>>();
+    // Compute edge points. The edge points of interior edges are weighted
+    // toward the endpoints of the edge and away from the opposing vertices of
+    // its adjacent triangles. The edge points of boundary edges are at their
+    // midpoints.
+    let mut edges = HashMap::with_capacity(graph.edge_count());
+    for edge in graph.edges() {
+        let arc = edge.arc();
+        let endpoints = arc.adjacent_vertices().map(|vertex| vertex.data);
+        let data = if edge.is_boundary_edge() {
+            G::Vertex::weighted_mean(endpoints.map(|data| (0.5, data)))
+        }
+        else {
+            G::Vertex::weighted_mean(
+                endpoints.map(|data| (0.375, data)).chain(
+                    [arc.to_ref(), arc.opposite_arc()]
+                        .iter()
+                        .map(|arc| (0.125, arc.next_arc().destination_vertex().data)),
+                ),
+            )
+        }
+        .expect_consistent();
+        edges.insert(edge.key(), (data, arc.key()));
+    }
+    // Compute vertex points. Interior vertices use the weights described by
+    // Loop. Boundary vertices are only affected by adjacent vertices along the
+    // boundary and non-manifold boundary vertices are not moved.
+    let mut vertices = Vec::with_capacity(graph.vertex_count());
+    for vertex in graph.vertices() {
+        let boundary = vertex
+            .outgoing_arcs()
+            .filter(|arc| arc.edge().is_boundary_edge())
+            .map(|arc| arc.destination_vertex().data)
+            .collect::<SmallVec<[_; 2]>>();
+        let data = match boundary.len() {
+            0 => {
+                let n = vertex.adjacent_vertices().count() as f64;
+                let beta = {
+                    let cos = (2.0 * PI / n).cos();
+                    (0.625 - (0.375 + (0.25 * cos)).powi(2)) / n
+                };
+                G::Vertex::weighted_mean(
+                    Some((1.0 - (n * beta), vertex.data))
+                        .into_iter()
+                        .chain(vertex.adjacent_vertices().map(|vertex| (beta, vertex.data))),
+                )
+                .expect_consistent()
+            }
+            2 => G::Vertex::weighted_mean(
+                Some((0.75, vertex.data))
+                    .into_iter()
+                    .chain(boundary.into_iter().map(|data| (0.125, data))),
+            )
+            .expect_consistent(),
+            _ => vertex.data,
+        };
+        vertices.push((vertex.key(), data));
+    }
+    Mutation::replace(graph, Default::default())
+        .commit_with(|mutation| {
+            let mutation: &mut Mutation<_> = mutation.as_mut();
+            let excision = face::excise(&mut *mutation, faces.iter().map(|(abc, _)| *abc))?;
+            for (a, data) in vertices {
+                mutation.replace_vertex_data(a, data)?;
+            }
+            let (edges, origins) = insert_edge_points(&mut *mutation, edges);
+            // Replace each triangle with a triangle at each vertex in its
+            // perimeter and a triangle formed from its edge points.
+            let mut triangles = Vec::with_capacity(faces.len() * 4);
+            for (abc, perimeter) in faces {
+                let data = *excision.face(abc).expect_consistent();
+                let mut points = SmallVec::<[_; 3]>::new();
+                for ((_, previous), (a, next)) in perimeter.into_iter().perimeter() {
+                    triangles.push((SmallVec::from([a, edges[&next], edges[&previous]]), data));
+                    points.push(edges[&next]);
+                }
+                triangles.push((points, data));
+            }
+            face::fill_with(&mut *mutation, excision, triangles, |ab| {
+                origins.get(&ab).cloned().unwrap_or(ab)
+            })
+        })
+        .expect_consistent();
+}
+
+// Inserts a vertex into each edge with the given data.
+//
+// Returns the inserted vertex of each edge and a mapping from the arcs between
+// the endpoints of each edge and its inserted vertex to the arcs that they
+// split. This mapping can be used with `face::fill_with` to retain arc and edge
+// data.
+fn insert_edge_points<G>(
+    mutation: &mut Mutation<MeshGraph<G>>,
+    edges: HashMap<EdgeKey, (G::Vertex, ArcKey)>,
+) -> (HashMap<EdgeKey, VertexKey>, HashMap<ArcKey, ArcKey>)
+where
+    G: GraphData,
+{
+    let mut origins = HashMap::with_capacity(edges.len() * 4);
+    let edges = edges
+        .into_iter()
+        .map(|(ab, (data, arc))| {
+            let m = vertex::insert(&mut *mutation, data);
+            let (a, b) = arc.into();
+            let opposite = arc.into_opposite();
+            origins.insert((a, m).into(), arc);
+            origins.insert((m, b).into(), arc);
+            origins.insert((b, m).into(), opposite);
+            origins.insert((m, a).into(), opposite);
+            (ab, m)
+        })
+        .collect();
+    (edges, origins)
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use num::Zero;
+    use theon::space::EuclideanSpace;
+
+    use crate::graph::{GraphError, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::Trigon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn subdivide_catmull_clark_cube() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let extent = graph
+            .vertices()
+            .map(|vertex| vertex.position().x)
+            .max()
+            .unwrap();
+        graph.subdivide_catmull_clark();
+
+        assert_eq!(26, graph.vertex_count());
+        assert_eq!(48, graph.edge_count());
+        assert_eq!(24, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+        // Vertices are pulled into the cube.
+        assert!(graph.vertices().all(|vertex| {
+            let position = vertex.position().into_coordinates();
+            position.iter().any(|x| -extent < *x && *x < extent)
+        }));
+    }
+
+    #[test]
+    fn subdivide_catmull_clark_boundary() {
+        let mut graph = MeshGraph::<E3>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3],
+            vec![
+                (0.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (2.0, 2.0, 0.0),
+                (0.0, 2.0, 0.0),
+            ],
+            4,
+        )
+        .unwrap();
+        graph.subdivide_catmull_clark();
+
+        assert_eq!(9, graph.vertex_count());
+        assert_eq!(4, graph.face_count());
+        // Edge points on the boundary are at the midpoints of edges and
+        // corners are smoothed along the boundary.
+        for &(x, y) in &[(1.0f64, 0.0f64), (1.0, 1.0), (0.25, 0.25)] {
+            let position = E3::new(x.into(), y.into(), R64::zero());
+            assert!(graph
+                .vertices()
+                .any(|vertex| *vertex.position() == position));
+        }
+    }
+
+    #[test]
+    fn subdivide_catmull_clark_with_creases() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let corners = graph
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        // Every edge of the cube is sharp, so corners are not moved and the
+        // surface remains a cube.
+        graph.subdivide_catmull_clark_with(|_| true);
+
+        assert_eq!(26, graph.vertex_count());
+        for corner in corners {
+            assert!(graph.vertices().any(|vertex| *vertex.position() == corner));
+        }
+    }
+
+    #[test]
+    fn subdivide_loop_tetrahedron() {
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![
+                Trigon::new(0usize, 1, 2),
+                Trigon::new(2, 1, 3),
+                Trigon::new(3, 0, 2),
+                Trigon::new(3, 1, 0),
+            ],
+            vec![
+                (0.0, 1.0, 0.0),
+                (-1.0, -1.0, 0.0),
+                (1.0, -1.0, 0.0),
+                (0.0, 0.0, 1.0),
+            ],
+        )
+        .unwrap();
+        graph.subdivide_loop(1).unwrap();
+
+        assert_eq!(10, graph.vertex_count());
+        assert_eq!(24, graph.edge_count());
+        assert_eq!(16, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        assert!(graph.arcs().all(|arc| !arc.is_boundary_arc()));
+
+        graph.subdivide_loop(2).unwrap();
+
+        assert_eq!(256, graph.face_count());
+    }
+
+    #[test]
+    fn subdivide_loop_boundary() {
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (0.0, 4.0, 0.0)],
+        )
+        .unwrap();
+        graph.subdivide_loop(1).unwrap();
+
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(4, graph.face_count());
+        // Edge points on the boundary are at the midpoints of edges and
+        // vertices are smoothed along the boundary.
+        for &(x, y) in &[(2.0f64, 0.0f64), (2.0, 2.0), (0.5, 0.5), (3.0, 0.5)] {
+            let position = E3::new(x.into(), y.into(), R64::zero());
+            assert!(graph
+                .vertices()
+                .any(|vertex| *vertex.position() == position));
+        }
+    }
+
+    #[test]
+    fn subdivide_loop_arity_error() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(
+            Err(GraphError::ArityConflict {
+                expected: 3,
+                actual: 4,
+            }),
+            graph.subdivide_loop(1),
+        );
+        assert_eq!(6, graph.face_count());
+    }
+}
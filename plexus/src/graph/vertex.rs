@@ -1,13 +1,15 @@
 use derivative::Derivative;
 use fool::BoolExt;
+use num::Zero;
 use slotmap::DefaultKey;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::mem;
 use std::ops::{Deref, DerefMut};
-use theon::space::Vector;
-use theon::AsPosition;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
+use theon::{AsPosition, AsPositionMut};
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
 use crate::entity::dijkstra;
@@ -20,7 +22,7 @@ use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
 use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView};
 use crate::graph::geometry::{VertexCentroid, VertexNormal, VertexPosition};
-use crate::graph::mutation::vertex::{self, VertexRemoveCache};
+use crate::graph::mutation::vertex::{self, VertexChamferCache, VertexRemoveCache};
 use crate::graph::mutation::{Consistent, Mutable, Mutation};
 use crate::graph::path::Path;
 use crate::graph::{GraphError, OptionExt as _, ResultExt as _};
@@ -244,6 +246,36 @@ where
     {
         <G as VertexCentroid>::centroid(self.to_ref()).expect_consistent()
     }
+
+    // Gets the data of corners placed along the edges of the vertex at the
+    // given distance. Corners are keyed by the vertex and the adjacent vertex
+    // toward which they are placed. Adjacent vertices in `excluded` are
+    // ignored.
+    pub(in crate::graph) fn corners(
+        &self,
+        distance: Scalar<VertexPosition<G>>,
+        excluded: &[VertexKey],
+    ) -> Result<HashMap<(VertexKey, VertexKey), G::Vertex>, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let origin = *self.position();
+        self.adjacent_vertices()
+            .filter(|vertex| !excluded.contains(&vertex.key()))
+            .map(|vertex| {
+                let direction = *vertex.position() - origin;
+                let length = direction.magnitude();
+                // Corners must be placed strictly within their edges.
+                if distance <= Zero::zero() || distance >= length {
+                    return Err(GraphError::Geometry);
+                }
+                let translation = direction * (distance / length);
+                let data = self.data.map_position(|position| *position + translation);
+                Ok(((self.key(), vertex.key()), data))
+            })
+            .collect()
+    }
 }
 
 impl<B, M, G> VertexView<B>
@@ -493,13 +525,12 @@ where
         + Mutable<Data = G>,
     G: GraphData,
 {
-    // TODO: This is not yet implemented, so examples use `no_run`. Run these
-    //       examples in doc tests once this no longer intentionally panics.
     /// Removes the vertex.
     ///
     /// Any and all dependent entities are also removed, such as arcs and edges
     /// connected to the vertex, faces connected to such arcs, vertices with no
-    /// remaining leading arc, etc.
+    /// remaining leading arc, etc. Edges that are no longer adjacent to any
+    /// faces are also removed.
     ///
     /// Vertex removal is the most destructive removal, because vertices are a
     /// dependency of all other entities.
@@ -508,7 +539,7 @@ where
     ///
     /// Removing a corner from a cube by removing its vertex:
     ///
-    /// ```rust,no_run
+    /// ```rust
     /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
@@ -527,6 +558,9 @@ where
     ///     .collect();
     /// let key = graph.vertices().nth(0).unwrap().key();
     /// graph.vertex_mut(key).unwrap().remove();
+    ///
+    /// assert_eq!(7, graph.vertex_count());
+    /// assert_eq!(3, graph.face_count());
     /// ```
     pub fn remove(self) {
         // This should never fail here.
@@ -537,6 +571,66 @@ where
             .map(|_| ())
             .expect_consistent()
     }
+
+    /// Chamfers the vertex.
+    ///
+    /// Chamfering replaces the vertex with a face. The corners of this face
+    /// are placed along each edge of the vertex at the given distance and each
+    /// adjacent face is cut by an edge between the corners along its arcs. If
+    /// the vertex is on a boundary, then the face is closed by a boundary edge.
+    ///
+    /// Returns the inserted face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the adjacent faces of the vertex do not form a
+    /// single fan of at least two faces, if the faces formed from corners would
+    /// be degenerate or conflict with each other, or if the distance is not
+    /// positive and less than the length of every edge of the vertex. The graph
+    /// is not modified if an error occurs.
+    ///
+    /// # Examples
+    ///
+    /// Chamfering a corner of a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new()
+    ///     .polygons::<Position<E3>>()
+    ///     .collect();
+    /// let key = graph.vertices().nth(0).unwrap().key();
+    /// let face = graph.vertex_mut(key).unwrap().chamfer(0.25).unwrap();
+    ///
+    /// assert_eq!(3, face.arity());
+    /// assert_eq!(10, graph.vertex_count());
+    /// assert_eq!(7, graph.face_count());
+    /// ```
+    pub fn chamfer<T>(self, distance: T) -> Result<FaceView<&'a mut M>, GraphError>
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let cache = VertexChamferCache::from_vertex(self.to_ref())?;
+        let corners = self.corners(distance.into(), &[])?;
+        let (storage, a) = self.unbind();
+        Ok(Mutation::replace(storage, Default::default())
+            .commit_with(|mutation| vertex::chamfer_with(mutation, cache, |b| corners[&(a, b)]))
+            .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
+            .expect_consistent())
+    }
 }
 
 impl<B, M, G> Adjacency for VertexView<B>
@@ -1074,12 +1168,12 @@ mod tests {
     use decorum::R64;
     use nalgebra::{Point2, Point3};
 
-    use crate::graph::MeshGraph;
+    use crate::graph::{GraphError, MeshGraph};
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
-    use crate::primitive::Trigon;
+    use crate::primitive::{Tetragon, Trigon};
 
     type E3 = Point3<R64>;
 
@@ -1112,6 +1206,91 @@ mod tests {
         assert_eq!(path.arcs().count(), 1);
     }
 
+    #[test]
+    fn remove() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
+            vec![(-1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (1.0, 0.0)],
+        )
+        .unwrap();
+
+        // Remove a vertex that is adjacent to only one of the triangles.
+        let key = graph
+            .vertices()
+            .find(|vertex| vertex.adjacent_faces().count() == 1)
+            .unwrap()
+            .key();
+        graph.vertex_mut(key).unwrap().remove();
+
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(3, graph.edge_count());
+        assert_eq!(1, graph.face_count());
+        assert!(graph.vertex(key).is_none());
+
+        // Remove a vertex of the remaining triangle. No faces remain, so no
+        // edges or vertices remain.
+        let key = graph.vertices().nth(0).unwrap().key();
+        graph.vertex_mut(key).unwrap().remove();
+
+        assert_eq!(0, graph.vertex_count());
+        assert_eq!(0, graph.arc_count());
+    }
+
+    #[test]
+    fn chamfer_vertex() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.vertices().nth(0).unwrap().key();
+        let face = graph.vertex_mut(key).unwrap().chamfer(0.25).unwrap();
+
+        assert_eq!(3, face.arity());
+        assert!(face.adjacent_faces().all(|face| face.arity() == 5));
+        assert!(graph.vertex(key).is_none());
+        assert_eq!(10, graph.vertex_count());
+        assert_eq!(15, graph.edge_count());
+        assert_eq!(7, graph.face_count());
+    }
+
+    #[test]
+    fn chamfer_boundary_vertex() {
+        // Construct a graph with two adjacent quadrilaterals.
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 4, 3), Tetragon::new(1, 2, 5, 4)],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (2.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let key = graph
+            .vertices()
+            .find(|vertex| vertex.adjacent_faces().count() == 2 && vertex.position().y < 0.5)
+            .unwrap()
+            .key();
+        let face = graph.vertex_mut(key).unwrap().chamfer(0.5).unwrap();
+
+        // The chamfer face is closed by a boundary edge.
+        assert_eq!(3, face.arity());
+        assert_eq!(2, face.adjacent_faces().count());
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(3, graph.face_count());
+    }
+
+    #[test]
+    fn chamfer_vertex_geometry_error() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.vertices().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::Geometry,
+            graph.vertex_mut(key).unwrap().chamfer(2.0).err().unwrap()
+        );
+        assert_eq!(8, graph.vertex_count());
+    }
+
     #[test]
     fn traverse_by_breadth() {
         let graph: MeshGraph<E3> = Cube::new()
@@ -57,8 +57,38 @@
 //!     .collect();
 //! ```
 //!
+//! Tessellating an [`UnboundedPolygon`] into [`Trigon`]s. Unlike other
+//! polygonal types, the tessellation considers the positions of vertices and
+//! supports concave polygons. Note that degenerate and self-intersecting
+//! polygons cannot be ear clipped and are tessellated into a triangle fan
+//! instead. [`BoundedPolygon`]s can be converted into [`UnboundedPolygon`]s to
+//! tessellate concave quadrilaterals.
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point2;
+//! use plexus::prelude::*;
+//! use plexus::primitive::{NGon, UnboundedPolygon};
+//!
+//! type E2 = Point2<f64>;
+//!
+//! // An L-shaped hexagon.
+//! let hexagon = UnboundedPolygon::from(NGon([
+//!     E2::new(0.0, 0.0),
+//!     E2::new(2.0, 0.0),
+//!     E2::new(2.0, 1.0),
+//!     E2::new(1.0, 1.0),
+//!     E2::new(1.0, 2.0),
+//!     E2::new(0.0, 2.0),
+//! ]));
+//! let trigons = hexagon.into_trigons();
+//! ```
+//!
 //! [`Iterator`]: std::iter::Iterator
 //! [`prelude`]: crate::prelude
+//! [`BoundedPolygon`]: crate::primitive::BoundedPolygon
 //! [`Decompose`]: crate::primitive::decompose::Decompose
 //! [`IntoTrigons`]: crate::primitive::decompose::IntoTrigons
 //! [`Triangulate`]: crate::primitive::decompose::Triangulate
@@ -66,12 +96,18 @@
 //! [`Polygonal`]: crate::primitive::Polygonal
 //! [`Tetragon`]: crate::primitive::Tetragon
 //! [`Trigon`]: crate::primitive::Trigon
+//! [`UnboundedPolygon`]: crate::primitive::UnboundedPolygon
 
 use arrayvec::ArrayVec;
 use std::collections::VecDeque;
 use std::iter::IntoIterator;
 use theon::adjunct::IntoItems;
 use theon::ops::Interpolate;
+use theon::space::FiniteDimensional;
+use theon::{AsPosition, Position};
+use typenum::{NonZero, Unsigned};
+
+use crate::geometry::triangulation::{self, Planar};
 
 use crate::primitive::{
     BoundedPolygon, Edge, Polygonal, Tetragon, Topological, Trigon, UnboundedPolygon,
@@ -293,6 +329,20 @@ where
     }
 }
 
+/// Decomposes a [`BoundedPolygon`] into [`Trigon`]s.
+///
+/// Unlike [`UnboundedPolygon`], this decomposition does not consider the
+/// positions of vertices and a [`Tetragon`] is always split along the diagonal
+/// between its first and third vertices, which is incorrect for some concave
+/// quadrilaterals. Bounded polygons are commonly used for indices, which have
+/// no positions, and this implementation cannot be specialized for types that
+/// do. To tessellate concave quadrilaterals, convert a [`BoundedPolygon`] into
+/// an [`UnboundedPolygon`] first.
+///
+/// [`BoundedPolygon`]: crate::primitive::BoundedPolygon
+/// [`Tetragon`]: crate::primitive::Tetragon
+/// [`Trigon`]: crate::primitive::Trigon
+/// [`UnboundedPolygon`]: crate::primitive::UnboundedPolygon
 impl<T> IntoTrigons for BoundedPolygon<T>
 where
     T: Clone,
@@ -307,6 +357,49 @@ where
     }
 }
 
+/// Decomposes an [`UnboundedPolygon`] into [`Trigon`]s by ear clipping.
+///
+/// Unlike other polygonal types, the arity of [`UnboundedPolygon`] is unbounded
+/// and it is not possible to choose an arbitrary decomposition that is correct
+/// for concave polygons. Instead, the polygon is projected into a plane and ear
+/// clipped.
+///
+/// **This decomposition never fails.** If ear clipping fails, such as for
+/// degenerate or self-intersecting polygons or polygons that cannot be
+/// projected into a plane, then the polygon is silently decomposed into a
+/// triangle fan about its first vertex and some of the resulting triangles
+/// may overlap or have reversed winding. Use [`MeshGraph::ear_clip`] to detect
+/// such polygons.
+///
+/// [`MeshGraph::ear_clip`]: crate::graph::MeshGraph::ear_clip
+/// [`Trigon`]: crate::primitive::Trigon
+/// [`UnboundedPolygon`]: crate::primitive::UnboundedPolygon
+impl<T, N> IntoTrigons for UnboundedPolygon<T>
+where
+    T: AsPosition + Clone,
+    Position<T>: Planar<N> + FiniteDimensional<N = N>,
+    N: NonZero + Unsigned,
+{
+    type Output = Vec<Trigon<Self::Vertex>>;
+
+    fn into_trigons(self) -> Self::Output {
+        let vertices = self.into_iter().collect::<Vec<_>>();
+        let triangles = Planar::project_planar(vertices.iter().map(|vertex| *vertex.as_position()))
+            .and_then(|points| triangulation::ear_clip_with(&points, |_, _| true))
+            .unwrap_or_else(|| (1..(vertices.len() - 1)).map(|i| [0, i, i + 1]).collect());
+        triangles
+            .into_iter()
+            .map(|[a, b, c]| {
+                Trigon::new(
+                    vertices[a].clone(),
+                    vertices[b].clone(),
+                    vertices[c].clone(),
+                )
+            })
+            .collect()
+    }
+}
+
 impl<T> IntoSubdivisions for Trigon<T>
 where
     T: Clone + Interpolate<Output = T>,
@@ -477,3 +570,39 @@ where
     }
     ngons
 }
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Point3;
+    use theon::space::EuclideanSpace;
+
+    use crate::prelude::*;
+    use crate::primitive::{NGon, Trigon, UnboundedPolygon};
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn unbounded_polygon_into_trigons() {
+        // A concave hexagon in the plane $x=y$.
+        let hexagon = UnboundedPolygon::from(NGon([
+            E3::from_xyz(0.0, 0.0, 0.0),
+            E3::from_xyz(2.0, 2.0, 0.0),
+            E3::from_xyz(2.0, 2.0, 1.0),
+            E3::from_xyz(1.0, 1.0, 1.0),
+            E3::from_xyz(1.0, 1.0, 2.0),
+            E3::from_xyz(0.0, 0.0, 2.0),
+        ]));
+        let trigons = hexagon.into_trigons();
+
+        assert_eq!(4, trigons.len());
+        // All triangles are wound in the same direction.
+        let normal = |trigon: &Trigon<E3>| {
+            let [a, b, c] = trigon.clone().into_array();
+            (b - a).cross(&(c - a))
+        };
+        let reference = normal(&trigons[0]);
+        for trigon in trigons.iter() {
+            assert!(normal(trigon).dot(&reference) > 0.0);
+        }
+    }
+}